@@ -0,0 +1,123 @@
+//! Reader for the Glyph Bitmap Distribution Format (BDF).
+
+use crate::{Bitmap, ConvertedFont, ConvertedGlyph, Result};
+
+/// Converts the characters from `first` to `last` of the BDF font in `text`.
+pub fn convert(text: &str, first: char, last: char) -> Result<ConvertedFont> {
+    let mut ascent = None;
+    let mut descent = None;
+    let mut bounding_box = None;
+    let mut glyphs: Vec<ConvertedGlyph> = (first..=last)
+        .map(|c| ConvertedGlyph {
+            c,
+            bitmap: Bitmap::default(),
+            advance: 0,
+        })
+        .collect();
+    let mut found = vec![false; glyphs.len()];
+
+    let mut lines = text.lines().enumerate();
+    while let Some((number, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("FONT_ASCENT") => ascent = Some(number_at(&mut words, number)?),
+            Some("FONT_DESCENT") => descent = Some(number_at(&mut words, number)?),
+            Some("FONTBOUNDINGBOX") => {
+                let height = number_at(&mut words.by_ref().skip(1), number)?;
+                let y = number_at(&mut words.skip(1), number)?;
+                bounding_box = Some((height, y));
+            }
+            Some("STARTCHAR") => {
+                let (encoding, glyph) = read_char(&mut lines)?;
+                let index = char::from_u32(encoding)
+                    .filter(|c| (first..=last).contains(c))
+                    .map(|c| c as usize - first as usize);
+                if let Some(index) = index {
+                    glyphs[index].advance = glyph.advance;
+                    glyphs[index].bitmap = glyph.bitmap;
+                    found[index] = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (ascent, descent) = match (ascent, descent, bounding_box) {
+        (Some(ascent), Some(descent), _) => (ascent, descent),
+        (_, _, Some((height, y))) => (height + y, -y),
+        _ => return Err("no FONT_ASCENT, FONT_DESCENT or FONTBOUNDINGBOX".to_string()),
+    };
+    for glyph in &mut glyphs {
+        // BDF positions the bitmaps relative to the baseline
+        glyph.bitmap.y_offset += ascent;
+    }
+    for (glyph, found) in glyphs.iter().zip(found) {
+        if !found {
+            eprintln!("font-converter: no glyph for {:?}, left empty", glyph.c);
+        }
+    }
+    Ok(ConvertedFont {
+        line_height: ascent + descent,
+        baseline: ascent,
+        glyphs,
+        kerning: Vec::new(),
+    })
+}
+
+/// Reads a character up to `ENDCHAR`, returns its encoding and glyph.
+fn read_char<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<(u32, ConvertedGlyph)> {
+    let mut encoding = None;
+    let mut advance = 0;
+    let mut bitmap = Bitmap::default();
+    while let Some((number, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("ENCODING") => {
+                // -1 for characters outside of the encoding
+                encoding = u32::try_from(number_at(&mut words, number)?).ok();
+            }
+            Some("DWIDTH") => advance = number_at(&mut words, number)?,
+            Some("BBX") => {
+                bitmap.width = number_at(&mut words, number)? as usize;
+                bitmap.height = number_at(&mut words, number)? as usize;
+                bitmap.x_offset = number_at(&mut words, number)?;
+                let y = number_at(&mut words, number)?;
+                bitmap.y_offset = -(y + bitmap.height as i32);
+            }
+            Some("BITMAP") => {
+                for _ in 0..bitmap.height {
+                    let (number, row) = lines.next().ok_or("truncated BITMAP")?;
+                    let bits = (0..bitmap.width).map(|x| {
+                        let digit = row.trim().get(x / 4..x / 4 + 1);
+                        let nibble = digit.and_then(|digit| u8::from_str_radix(digit, 16).ok());
+                        nibble
+                            .map(|nibble| nibble & (0x8 >> (x % 4)) != 0)
+                            .ok_or(format!("line {}: invalid BITMAP row", number + 1))
+                    });
+                    for bit in bits {
+                        bitmap.pixels.push(bit?);
+                    }
+                }
+            }
+            Some("ENDCHAR") => {
+                let glyph = ConvertedGlyph {
+                    c: '\0',
+                    bitmap,
+                    advance,
+                };
+                return Ok((encoding.unwrap_or(u32::MAX), glyph));
+            }
+            _ => {}
+        }
+    }
+    Err("missing ENDCHAR".to_string())
+}
+
+fn number_at<'a>(words: &mut impl Iterator<Item = &'a str>, line: usize) -> Result<i32> {
+    words
+        .next()
+        .and_then(|word| word.parse().ok())
+        .ok_or(format!("line {}: expected a number", line + 1))
+}
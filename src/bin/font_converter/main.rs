@@ -0,0 +1,301 @@
+//! Converts a TrueType (`.ttf`) or BDF (`.bdf`) font to a `const` [`Font`] table of this crate.
+//!
+//! ```text
+//! font-converter <FONT> <NAME> [--size PIXELS] [--first CHAR] [--last CHAR]
+//!                [--threshold COVERAGE] [--output FILE]
+//! ```
+//!
+//! TrueType outlines are rasterized at `--size` pixels per em without anti-aliasing: a pixel
+//! is set when at least `--threshold` (0.5 by default) of it is covered. The advance widths and
+//! the kerning of the font (`GPOS` pair adjustments, or the legacy `kern` table) are kept.
+//! BDF fonts are already bitmaps and are converted as they are, without kerning.
+//!
+//! The characters from `--first` to `--last` (`' '` to `'~'` by default, given as a character
+//! or a code point like `0x20`) are converted. The generated module is written to `--output`
+//! or to the standard output, include it in a `no_std` crate to get a `pub const NAME: Font`.
+//!
+//! [`Font`]: e_ink_graphics_library::font::Font
+
+mod bdf;
+mod raster;
+mod truetype;
+
+use std::fmt::Write;
+use std::process::ExitCode;
+use std::{env, fs};
+
+type Result<T> = core::result::Result<T, String>;
+
+/// Glyph rendered to one `bool` per pixel, `true` for the pixels of the character.
+#[derive(Debug, Default)]
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    /// Position of the top left pixel relative to the top left corner of the character cell.
+    pub x_offset: i32,
+    pub y_offset: i32,
+    pub pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Removes the empty rows and columns around the pixels of the character.
+    fn crop(&self) -> Bitmap {
+        let rows = (0..self.height).filter(|&y| (0..self.width).any(|x| self.pixel(x, y)));
+        let columns = (0..self.width).filter(|&x| (0..self.height).any(|y| self.pixel(x, y)));
+        let (Some(top), Some(bottom)) = (rows.clone().min(), rows.max()) else {
+            return Bitmap::default();
+        };
+        let left = columns.clone().min().unwrap_or(0);
+        let right = columns.max().unwrap_or(0);
+        let mut pixels = Vec::new();
+        for y in top..=bottom {
+            pixels.extend((left..=right).map(|x| self.pixel(x, y)));
+        }
+        Bitmap {
+            width: right - left + 1,
+            height: bottom - top + 1,
+            x_offset: self.x_offset + left as i32,
+            y_offset: self.y_offset + top as i32,
+            pixels,
+        }
+    }
+
+    /// Packs the rows in bytes, most significant bit first, set for the pixels of the character.
+    fn pack(&self, bytes: &mut Vec<u8>) {
+        for y in 0..self.height {
+            for start in (0..self.width).step_by(8) {
+                let mut byte = 0u8;
+                for x in start..self.width.min(start + 8) {
+                    if self.pixel(x, y) {
+                        byte |= 0x80 >> (x - start);
+                    }
+                }
+                bytes.push(byte);
+            }
+        }
+    }
+}
+
+/// Glyph of a converted font.
+#[derive(Debug)]
+pub struct ConvertedGlyph {
+    pub c: char,
+    pub bitmap: Bitmap,
+    pub advance: i32,
+}
+
+/// Font converted to pixels, before being written as Rust.
+#[derive(Debug)]
+pub struct ConvertedFont {
+    pub line_height: i32,
+    pub baseline: i32,
+    pub glyphs: Vec<ConvertedGlyph>,
+    /// Sorted by left then right character.
+    pub kerning: Vec<(char, char, i32)>,
+}
+
+struct Options {
+    input: String,
+    name: String,
+    size: Option<f32>,
+    first: char,
+    last: char,
+    threshold: f32,
+    output: Option<String>,
+}
+
+const USAGE: &str = "usage: font-converter <FONT> <NAME> [--size PIXELS] [--first CHAR] \
+                     [--last CHAR] [--threshold COVERAGE] [--output FILE]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("font-converter: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<()> {
+    let options = parse_options(env::args().skip(1))?;
+    let data = fs::read(&options.input).map_err(|e| format!("{}: {e}", options.input))?;
+    let font = if options.input.to_ascii_lowercase().ends_with(".bdf") {
+        let text = String::from_utf8(data).map_err(|_| "BDF file is not UTF-8".to_string())?;
+        bdf::convert(&text, options.first, options.last)?
+    } else {
+        let size = options
+            .size
+            .ok_or("--size is required for TrueType fonts")?;
+        truetype::convert(&data, size, options.first, options.last, options.threshold)?
+    };
+    let source = generate(&font, &options)?;
+    match &options.output {
+        Some(path) => fs::write(path, source).map_err(|e| format!("{path}: {e}")),
+        None => {
+            print!("{source}");
+            Ok(())
+        }
+    }
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options> {
+    let mut positional = Vec::new();
+    let mut options = Options {
+        input: String::new(),
+        name: String::new(),
+        size: None,
+        first: ' ',
+        last: '~',
+        threshold: 0.5,
+        output: None,
+    };
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            positional.push(arg);
+            continue;
+        }
+        let value = args.next().ok_or(format!("missing value for {arg}"))?;
+        let number = || {
+            value
+                .parse::<f32>()
+                .map_err(|_| format!("invalid {arg}: {value}"))
+        };
+        match arg.as_str() {
+            "--size" => options.size = Some(number()?),
+            "--threshold" => options.threshold = number()?,
+            "--first" => options.first = parse_char(&value)?,
+            "--last" => options.last = parse_char(&value)?,
+            "--output" => options.output = Some(value),
+            _ => return Err(format!("unknown option {arg}\n{USAGE}")),
+        }
+    }
+    let [input, name] = <[String; 2]>::try_from(positional).map_err(|_| USAGE.to_string())?;
+    if options.first > options.last {
+        return Err("--first is after --last".to_string());
+    }
+    options.input = input;
+    options.name = name;
+    Ok(options)
+}
+
+/// Parses a single character or a decimal or `0x` hexadecimal code point.
+fn parse_char(value: &str) -> Result<char> {
+    let mut chars = value.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(c);
+    }
+    let code = match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    code.ok()
+        .and_then(char::from_u32)
+        .ok_or(format!("invalid character: {value}"))
+}
+
+fn generate(font: &ConvertedFont, options: &Options) -> Result<String> {
+    let mut glyphs = String::new();
+    let mut bitmaps = Vec::new();
+    for glyph in &font.glyphs {
+        let bitmap = glyph.bitmap.crop();
+        writeln!(
+            glyphs,
+            "    Glyph {{ offset: {}, width: {}, height: {}, x_offset: {}, y_offset: {}, \
+             advance: {} }}, // {:?}",
+            bitmaps.len(),
+            fit::<u8>(bitmap.width, "glyph width", glyph.c)?,
+            fit::<u8>(bitmap.height, "glyph height", glyph.c)?,
+            fit::<i8>(bitmap.x_offset, "glyph x offset", glyph.c)?,
+            fit::<i8>(bitmap.y_offset, "glyph y offset", glyph.c)?,
+            fit::<u8>(glyph.advance, "advance", glyph.c)?,
+            glyph.c,
+        )
+        .unwrap();
+        bitmap.pack(&mut bitmaps);
+    }
+    let mut kerning = String::new();
+    for &(left, right, adjust) in &font.kerning {
+        writeln!(
+            kerning,
+            "    KerningPair {{ left: {left:?}, right: {right:?}, adjust: {} }},",
+            fit::<i8>(adjust, "kerning", left)?,
+        )
+        .unwrap();
+    }
+
+    let input = options.input.rsplit(['/', '\\']).next().unwrap_or_default();
+    let size = options
+        .size
+        .filter(|_| !input.to_ascii_lowercase().ends_with(".bdf"))
+        .map(|size| format!(" at {size} pixels"))
+        .unwrap_or_default();
+    let mut source = String::new();
+    writeln!(
+        source,
+        "//! Font generated by `font-converter` from {input}{size}."
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "use e_ink_graphics_library::font::{{Font, Glyph, KerningPair}};"
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "pub const {}: Font = Font {{", options.name).unwrap();
+    writeln!(source, "    first_char: {:?},", options.first).unwrap();
+    writeln!(
+        source,
+        "    line_height: {},",
+        fit::<u8>(font.line_height, "line height", options.first)?
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "    baseline: {},",
+        fit::<u8>(font.baseline, "baseline", options.first)?
+    )
+    .unwrap();
+    writeln!(source, "    glyphs: &GLYPHS,").unwrap();
+    writeln!(source, "    bitmaps: &BITMAPS,").unwrap();
+    writeln!(source, "    kerning: &KERNING,").unwrap();
+    writeln!(source, "}};").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "#[rustfmt::skip]").unwrap();
+    writeln!(source, "const GLYPHS: [Glyph; {}] = [", font.glyphs.len()).unwrap();
+    source.push_str(&glyphs);
+    writeln!(source, "];").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        source,
+        "const KERNING: [KerningPair; {}] = [",
+        font.kerning.len()
+    )
+    .unwrap();
+    source.push_str(&kerning);
+    writeln!(source, "];").unwrap();
+    writeln!(source).unwrap();
+    writeln!(source, "#[rustfmt::skip]").unwrap();
+    writeln!(source, "const BITMAPS: [u8; {}] = [", bitmaps.len()).unwrap();
+    for row in bitmaps.chunks(12) {
+        let bytes: Vec<String> = row.iter().map(|byte| format!("0x{byte:02x},")).collect();
+        writeln!(source, "    {}", bytes.join(" ")).unwrap();
+    }
+    writeln!(source, "];").unwrap();
+    Ok(source)
+}
+
+/// Converts `value` to the type of a field of the glyph format, or explains why it does not fit.
+fn fit<T: TryFrom<i64>>(value: impl TryInto<i64>, what: &str, c: char) -> Result<T> {
+    value
+        .try_into()
+        .ok()
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or(format!("{what} of {c:?} does not fit in the glyph format"))
+}
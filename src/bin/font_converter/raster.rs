@@ -0,0 +1,147 @@
+//! Scanline rasterizer for quadratic glyph outlines, without anti-aliasing.
+
+use crate::Bitmap;
+
+/// Point of a glyph outline in font units, the y axis going up.
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+    pub on_curve: bool,
+}
+
+/// Samples per pixel along each axis.
+const SUBSAMPLES: usize = 4;
+/// Segments per quadratic curve.
+const CURVE_STEPS: usize = 8;
+
+/// Renders `contours` scaled by `scale` with the origin `baseline` pixels below the top of
+/// the character cell. A pixel is set when at least `threshold` of its samples are inside the
+/// outline, using the non-zero winding rule.
+pub fn rasterize(contours: &[Vec<Point>], scale: f32, baseline: f32, threshold: f32) -> Bitmap {
+    // edges in pixels, the y axis going down
+    let mut edges = Vec::new();
+    for polygon in contours.iter().map(|contour| flatten(contour)) {
+        for (i, &(x0, y0)) in polygon.iter().enumerate() {
+            let (x1, y1) = polygon[(i + 1) % polygon.len()];
+            let start = (x0 * scale, baseline - y0 * scale);
+            let end = (x1 * scale, baseline - y1 * scale);
+            if start.1 != end.1 {
+                edges.push((start, end));
+            }
+        }
+    }
+    if edges.is_empty() {
+        return Bitmap::default();
+    }
+
+    let points = edges.iter().map(|&(start, _)| start);
+    let (mut left, mut top, mut right, mut bottom) = (f32::MAX, f32::MAX, f32::MIN, f32::MIN);
+    for (x, y) in points {
+        left = left.min(x);
+        right = right.max(x);
+        top = top.min(y);
+        bottom = bottom.max(y);
+    }
+    let (x_offset, y_offset) = (left.floor() as i32, top.floor() as i32);
+    let width = (right.ceil() as i32 - x_offset).max(1) as usize;
+    let height = (bottom.ceil() as i32 - y_offset).max(1) as usize;
+
+    let mut coverage = vec![0usize; width * height];
+    let mut crossings = Vec::new();
+    for row in 0..height {
+        for sub_row in 0..SUBSAMPLES {
+            let y = (y_offset + row as i32) as f32 + (sub_row as f32 + 0.5) / SUBSAMPLES as f32;
+            crossings.clear();
+            for &((x0, y0), (x1, y1)) in &edges {
+                if (y0 <= y && y < y1) || (y1 <= y && y < y0) {
+                    let x = x0 + (y - y0) / (y1 - y0) * (x1 - x0);
+                    crossings.push((x, if y1 > y0 { 1 } else { -1 }));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let mut winding = 0;
+            let mut next = 0;
+            for column in 0..width * SUBSAMPLES {
+                let x = x_offset as f32 + (column as f32 + 0.5) / SUBSAMPLES as f32;
+                while next < crossings.len() && crossings[next].0 < x {
+                    winding += crossings[next].1;
+                    next += 1;
+                }
+                if winding != 0 {
+                    coverage[row * width + column / SUBSAMPLES] += 1;
+                }
+            }
+        }
+    }
+
+    let samples = (SUBSAMPLES * SUBSAMPLES) as f32;
+    Bitmap {
+        width,
+        height,
+        x_offset,
+        y_offset,
+        pixels: coverage
+            .into_iter()
+            .map(|count| count as f32 >= threshold * samples)
+            .collect(),
+    }
+}
+
+/// Approximates a contour made of lines and quadratic curves with a polygon.
+fn flatten(contour: &[Point]) -> Vec<(f32, f32)> {
+    let Some(start) = contour.iter().position(|point| point.on_curve) else {
+        // only control points: the curves start between the first two
+        return match contour {
+            [a, b, ..] => {
+                let mut rotated = contour[1..].to_vec();
+                rotated.push(*a);
+                let middle = Point {
+                    x: (a.x + b.x) / 2.0,
+                    y: (a.y + b.y) / 2.0,
+                    on_curve: true,
+                };
+                rotated.insert(0, middle);
+                flatten(&rotated)
+            }
+            _ => Vec::new(),
+        };
+    };
+    let first = (contour[start].x, contour[start].y);
+    let mut polygon = vec![first];
+    let mut current = first;
+    let mut control: Option<(f32, f32)> = None;
+    let following = contour[start + 1..].iter().chain(&contour[..=start]);
+    for point in following {
+        let position = (point.x, point.y);
+        match (point.on_curve, control) {
+            (true, None) => polygon.push(position),
+            (true, Some(c)) => curve(&mut polygon, current, c, position),
+            (false, None) => {}
+            (false, Some(c)) => {
+                let middle = ((c.0 + position.0) / 2.0, (c.1 + position.1) / 2.0);
+                curve(&mut polygon, current, c, middle);
+                current = middle;
+            }
+        }
+        if point.on_curve {
+            current = position;
+            control = None;
+        } else {
+            control = Some(position);
+        }
+    }
+    polygon
+}
+
+fn curve(polygon: &mut Vec<(f32, f32)>, from: (f32, f32), control: (f32, f32), to: (f32, f32)) {
+    for step in 1..=CURVE_STEPS {
+        let t = step as f32 / CURVE_STEPS as f32;
+        let (a, b, c) = ((1.0 - t) * (1.0 - t), 2.0 * (1.0 - t) * t, t * t);
+        polygon.push((
+            a * from.0 + b * control.0 + c * to.0,
+            a * from.1 + b * control.1 + c * to.1,
+        ));
+    }
+}
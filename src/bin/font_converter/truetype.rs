@@ -0,0 +1,599 @@
+//! Minimal TrueType reader: glyph outlines, metrics, character map and kerning.
+
+use crate::raster::{Point, rasterize};
+use crate::{ConvertedFont, ConvertedGlyph, Result};
+
+/// Rasterizes the characters from `first` to `last` of the TrueType font in `data`.
+pub fn convert(
+    data: &[u8],
+    size: f32,
+    first: char,
+    last: char,
+    threshold: f32,
+) -> Result<ConvertedFont> {
+    let font = TrueType::parse(data)?;
+    let scale = size / font.units_per_em as f32;
+    let ascent = (font.ascent as f32 * scale).round();
+    let descent = (-font.descent as f32 * scale).round();
+    let line_gap = (font.line_gap as f32 * scale).round();
+
+    let mut glyphs = Vec::new();
+    let mut indices = Vec::new();
+    for c in first..=last {
+        let index = font.glyph_index(c)?;
+        if index == 0 {
+            eprintln!("font-converter: no glyph for {c:?}, left empty");
+        }
+        let contours = font.contours(index, 0)?;
+        glyphs.push(ConvertedGlyph {
+            c,
+            bitmap: rasterize(&contours, scale, ascent, threshold),
+            advance: (font.advance(index)? as f32 * scale).round() as i32,
+        });
+        indices.push((c, index));
+    }
+
+    let mut kerning = Vec::new();
+    for &(left, left_index) in &indices {
+        for &(right, right_index) in &indices {
+            if left_index == 0 || right_index == 0 {
+                continue;
+            }
+            let adjust = (font.kerning(left_index, right_index)? as f32 * scale).round() as i32;
+            if adjust != 0 {
+                kerning.push((left, right, adjust));
+            }
+        }
+    }
+
+    Ok(ConvertedFont {
+        line_height: (ascent + descent + line_gap) as i32,
+        baseline: ascent as i32,
+        glyphs,
+        kerning,
+    })
+}
+
+struct TrueType<'a> {
+    data: &'a [u8],
+    units_per_em: u16,
+    ascent: i16,
+    descent: i16,
+    line_gap: i16,
+    long_metrics: u16,
+    long_loca: bool,
+    /// Offset and format of the Unicode character map.
+    cmap: (usize, u16),
+    hmtx: usize,
+    loca: usize,
+    glyf: usize,
+    kern: Option<usize>,
+    gpos: Option<usize>,
+}
+
+impl<'a> TrueType<'a> {
+    fn parse(data: &'a [u8]) -> Result<Self> {
+        let mut font = TrueType {
+            data,
+            units_per_em: 0,
+            ascent: 0,
+            descent: 0,
+            line_gap: 0,
+            long_metrics: 0,
+            long_loca: false,
+            cmap: (0, 0),
+            hmtx: 0,
+            loca: 0,
+            glyf: 0,
+            kern: None,
+            gpos: None,
+        };
+        let table = |tag: &[u8]| font.table(tag);
+        let head = table(b"head")?.ok_or("not a TrueType font: no head table")?;
+        let hhea = table(b"hhea")?.ok_or("no hhea table")?;
+        let cmap = table(b"cmap")?.ok_or("no cmap table")?;
+        let hmtx = table(b"hmtx")?.ok_or("no hmtx table")?;
+        let loca = table(b"loca")?.ok_or("no loca table, CFF outlines are not supported")?;
+        let glyf = table(b"glyf")?.ok_or("no glyf table, CFF outlines are not supported")?;
+        let kern = table(b"kern")?;
+        let gpos = table(b"GPOS")?;
+
+        font.units_per_em = match font.u16(head + 18)? {
+            0 => return Err("corrupted head table: 0 units per em".to_string()),
+            units => units,
+        };
+        font.long_loca = match font.i16(head + 50)? {
+            0 => false,
+            1 => true,
+            format => return Err(format!("corrupted head table: loca format {format}")),
+        };
+        font.ascent = font.i16(hhea + 4)?;
+        font.descent = font.i16(hhea + 6)?;
+        font.line_gap = font.i16(hhea + 8)?;
+        font.long_metrics = match font.u16(hhea + 34)? {
+            0 => return Err("corrupted hhea table: no horizontal metrics".to_string()),
+            count => count,
+        };
+        font.cmap = font.unicode_cmap(cmap)?;
+        font.hmtx = hmtx;
+        font.loca = loca;
+        font.glyf = glyf;
+        font.kern = kern;
+        font.gpos = gpos;
+        Ok(font)
+    }
+
+    fn table(&self, tag: &[u8]) -> Result<Option<usize>> {
+        for i in 0..self.u16(4)? as usize {
+            let record = 12 + 16 * i;
+            if self.bytes(record, 4)? == tag {
+                return Ok(Some(self.u32(record + 8)? as usize));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds a subtable of `cmap` mapping Unicode characters, preferring the full repertoire.
+    fn unicode_cmap(&self, cmap: usize) -> Result<(usize, u16)> {
+        let mut found = None;
+        for i in 0..self.u16(cmap + 2)? as usize {
+            let record = cmap + 4 + 8 * i;
+            let platform = self.u16(record)?;
+            let encoding = self.u16(record + 2)?;
+            let subtable = cmap + self.u32(record + 4)? as usize;
+            let format = self.u16(subtable)?;
+            let unicode = platform == 0 || (platform == 3 && (encoding == 1 || encoding == 10));
+            if unicode && format == 12 {
+                return Ok((subtable, format));
+            }
+            if unicode && format == 4 {
+                found = Some((subtable, format));
+            }
+        }
+        found.ok_or("no Unicode character map".to_string())
+    }
+
+    fn glyph_index(&self, c: char) -> Result<u16> {
+        let c = c as u32;
+        let (cmap, format) = self.cmap;
+        if format == 12 {
+            for i in 0..self.u32(cmap + 12)? as usize {
+                let group = cmap + 16 + 12 * i;
+                let start = self.u32(group)?;
+                if (start..=self.u32(group + 4)?).contains(&c) {
+                    return self
+                        .u32(group + 8)?
+                        .checked_add(c - start)
+                        .and_then(|index| u16::try_from(index).ok())
+                        .ok_or("corrupted cmap table: glyph index out of range".to_string());
+                }
+            }
+            return Ok(0);
+        }
+        if c > 0xffff {
+            return Ok(0);
+        }
+        let segments = self.u16(cmap + 6)? as usize / 2;
+        let ends = cmap + 14;
+        let starts = ends + 2 * segments + 2;
+        let deltas = starts + 2 * segments;
+        let range_offsets = deltas + 2 * segments;
+        for s in 0..segments {
+            if c > self.u16(ends + 2 * s)? as u32 {
+                continue;
+            }
+            let start = self.u16(starts + 2 * s)? as u32;
+            if c < start {
+                return Ok(0);
+            }
+            let delta = self.u16(deltas + 2 * s)?;
+            let range_offset = self.u16(range_offsets + 2 * s)? as usize;
+            if range_offset == 0 {
+                return Ok((c as u16).wrapping_add(delta));
+            }
+            let address = range_offsets + 2 * s + range_offset + 2 * (c - start) as usize;
+            let index = self.u16(address)?;
+            return Ok(if index == 0 {
+                0
+            } else {
+                index.wrapping_add(delta)
+            });
+        }
+        Ok(0)
+    }
+
+    fn advance(&self, glyph: u16) -> Result<u16> {
+        let metric = glyph.min(self.long_metrics - 1) as usize;
+        self.u16(self.hmtx + 4 * metric)
+    }
+
+    /// Returns the outline of `glyph` in font units, one list of points per contour.
+    fn contours(&self, glyph: u16, depth: u32) -> Result<Vec<Vec<Point>>> {
+        if depth > 8 {
+            return Err("too many nested composite glyphs".to_string());
+        }
+        let glyph = glyph as usize;
+        let (start, end) = if self.long_loca {
+            (
+                self.u32(self.loca + 4 * glyph)? as usize,
+                self.u32(self.loca + 4 * glyph + 4)? as usize,
+            )
+        } else {
+            (
+                self.u16(self.loca + 2 * glyph)? as usize * 2,
+                self.u16(self.loca + 2 * glyph + 2)? as usize * 2,
+            )
+        };
+        if start == end {
+            return Ok(Vec::new());
+        }
+        if start > end {
+            return Err(format!("corrupted loca table at glyph {glyph}"));
+        }
+        let offset = self.glyf + start;
+        let contour_count = self.i16(offset)?;
+        if contour_count < 0 {
+            self.composite_contours(offset + 10, depth)
+        } else {
+            self.simple_contours(offset + 10, contour_count as usize)
+        }
+    }
+
+    fn simple_contours(&self, mut p: usize, contour_count: usize) -> Result<Vec<Vec<Point>>> {
+        let mut ends: Vec<usize> = Vec::new();
+        for i in 0..contour_count {
+            let end = self.u16(p + 2 * i)? as usize;
+            if ends.last().is_some_and(|&previous| end <= previous) {
+                return Err("corrupted glyph: contour ends out of order".to_string());
+            }
+            ends.push(end);
+        }
+        p += 2 * contour_count;
+        p += 2 + self.u16(p)? as usize;
+        let point_count = ends.last().map_or(0, |end| end + 1);
+
+        let mut flags = Vec::new();
+        while flags.len() < point_count {
+            let flag = self.u8(p)?;
+            p += 1;
+            flags.push(flag);
+            if flag & 0x08 != 0 {
+                let repeat = self.u8(p)?;
+                p += 1;
+                flags.extend((0..repeat).map(|_| flag));
+            }
+        }
+        let mut coordinates = [Vec::new(), Vec::new()];
+        for (axis, coordinates) in coordinates.iter_mut().enumerate() {
+            let (short, same_or_positive) = (0x02 << axis, 0x10 << axis);
+            let mut value = 0i32;
+            for &flag in &flags[..point_count] {
+                if flag & short != 0 {
+                    let delta = self.u8(p)? as i32;
+                    p += 1;
+                    value += if flag & same_or_positive != 0 {
+                        delta
+                    } else {
+                        -delta
+                    };
+                } else if flag & same_or_positive == 0 {
+                    value += self.i16(p)? as i32;
+                    p += 2;
+                }
+                coordinates.push(value as f32);
+            }
+        }
+
+        let mut contours = Vec::new();
+        let mut first = 0;
+        for end in ends {
+            let points = (first..=end).map(|i| {
+                let point = coordinates[0]
+                    .get(i)
+                    .zip(coordinates[1].get(i))
+                    .zip(flags.get(i));
+                point
+                    .map(|((&x, &y), flag)| Point {
+                        x,
+                        y,
+                        on_curve: flag & 0x01 != 0,
+                    })
+                    .ok_or("corrupted glyph: contour end past the last point".to_string())
+            });
+            contours.push(points.collect::<Result<_>>()?);
+            first = end + 1;
+        }
+        Ok(contours)
+    }
+
+    /// Assembles the components of a composite glyph, placed by an offset or by matching one of
+    /// their points with a point of the components before them.
+    fn composite_contours(&self, mut p: usize, depth: u32) -> Result<Vec<Vec<Point>>> {
+        let mut contours: Vec<Vec<Point>> = Vec::new();
+        loop {
+            let flags = self.u16(p)?;
+            let glyph = self.u16(p + 2)?;
+            p += 4;
+            let words = flags & 0x0001 != 0;
+            let args = if words {
+                p += 4;
+                (self.u16(p - 4)?, self.u16(p - 2)?)
+            } else {
+                p += 2;
+                (self.u8(p - 2)? as u16, self.u8(p - 1)? as u16)
+            };
+            let f2dot14 = |offset: usize| Ok::<_, String>(self.i16(offset)? as f32 / 16384.0);
+            let (sx, sy) = if flags & 0x0008 != 0 {
+                p += 2;
+                let scale = f2dot14(p - 2)?;
+                (scale, scale)
+            } else if flags & 0x0040 != 0 {
+                p += 4;
+                (f2dot14(p - 4)?, f2dot14(p - 2)?)
+            } else if flags & 0x0080 != 0 {
+                p += 8;
+                (f2dot14(p - 8)?, f2dot14(p - 2)?)
+            } else {
+                (1.0, 1.0)
+            };
+            let component: Vec<Vec<Point>> = self
+                .contours(glyph, depth + 1)?
+                .into_iter()
+                .map(|contour| {
+                    let points = contour.into_iter().map(|point| Point {
+                        x: point.x * sx,
+                        y: point.y * sy,
+                        on_curve: point.on_curve,
+                    });
+                    points.collect()
+                })
+                .collect();
+            let (dx, dy) = if flags & 0x0002 != 0 {
+                // signed offsets
+                if words {
+                    (args.0 as i16 as f32, args.1 as i16 as f32)
+                } else {
+                    (args.0 as u8 as i8 as f32, args.1 as u8 as i8 as f32)
+                }
+            } else {
+                // the point `args.1` of the component lands on the point `args.0` of the glyph
+                let glyph_point = contours.iter().flatten().nth(args.0 as usize);
+                let component_point = component.iter().flatten().nth(args.1 as usize);
+                let (Some(glyph_point), Some(component_point)) = (glyph_point, component_point)
+                else {
+                    return Err("corrupted composite glyph: matched point out of range".to_string());
+                };
+                (
+                    glyph_point.x - component_point.x,
+                    glyph_point.y - component_point.y,
+                )
+            };
+            for contour in component {
+                let points = contour.into_iter().map(|point| Point {
+                    x: point.x + dx,
+                    y: point.y + dy,
+                    on_curve: point.on_curve,
+                });
+                contours.push(points.collect());
+            }
+            if flags & 0x0020 == 0 {
+                return Ok(contours);
+            }
+        }
+    }
+
+    /// Horizontal adjustment between `left` and `right` in font units, from the `GPOS` pair
+    /// adjustments if the font has them, otherwise from the `kern` table.
+    fn kerning(&self, left: u16, right: u16) -> Result<i16> {
+        if let Some(gpos) = self.gpos {
+            return self.gpos_kerning(gpos, left, right);
+        }
+        let Some(kern) = self.kern else {
+            return Ok(0);
+        };
+        let mut subtable = kern + 4;
+        for _ in 0..self.u16(kern + 2)? {
+            let length = self.u16(subtable + 2)? as usize;
+            let coverage = self.u16(subtable + 4)?;
+            // format 0 horizontal kerning values
+            if coverage >> 8 == 0 && coverage & 0x0007 == 0x0001 {
+                let pair_count = self.u16(subtable + 6)? as usize;
+                let key = (left as u32) << 16 | right as u32;
+                let pairs = subtable + 14;
+                let (mut low, mut high) = (0, pair_count);
+                while low < high {
+                    let middle = (low + high) / 2;
+                    let pair = self.u32(pairs + 6 * middle)?;
+                    if pair == key {
+                        return self.i16(pairs + 6 * middle + 4);
+                    } else if pair < key {
+                        low = middle + 1;
+                    } else {
+                        high = middle;
+                    }
+                }
+            }
+            subtable += length;
+        }
+        Ok(0)
+    }
+
+    fn gpos_kerning(&self, gpos: usize, left: u16, right: u16) -> Result<i16> {
+        let features = gpos + self.u16(gpos + 6)? as usize;
+        let lookups = gpos + self.u16(gpos + 8)? as usize;
+        let mut total: i16 = 0;
+        let mut seen = Vec::new();
+        for i in 0..self.u16(features)? as usize {
+            let record = features + 2 + 6 * i;
+            if self.bytes(record, 4)? != b"kern" {
+                continue;
+            }
+            let feature = features + self.u16(record + 4)? as usize;
+            for j in 0..self.u16(feature + 2)? as usize {
+                let index = self.u16(feature + 4 + 2 * j)?;
+                if seen.contains(&index) {
+                    continue;
+                }
+                seen.push(index);
+                let lookup = lookups + self.u16(lookups + 2 + 2 * index as usize)? as usize;
+                let adjust = self.lookup_kerning(lookup, left, right)?;
+                total = total
+                    .checked_add(adjust)
+                    .ok_or("corrupted GPOS table: kerning out of range".to_string())?;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Applies the first subtable of a pair adjustment lookup that covers the pair.
+    fn lookup_kerning(&self, lookup: usize, left: u16, right: u16) -> Result<i16> {
+        let lookup_type = self.u16(lookup)?;
+        for i in 0..self.u16(lookup + 4)? as usize {
+            let mut subtable = lookup + self.u16(lookup + 6 + 2 * i)? as usize;
+            let mut subtable_type = lookup_type;
+            // extension subtables point to the actual subtable
+            if subtable_type == 9 {
+                subtable_type = self.u16(subtable + 2)?;
+                subtable += self.u32(subtable + 4)? as usize;
+            }
+            if subtable_type != 2 {
+                continue;
+            }
+            if let Some(adjust) = self.pair_adjustment(subtable, left, right)? {
+                return Ok(adjust);
+            }
+        }
+        Ok(0)
+    }
+
+    fn pair_adjustment(&self, subtable: usize, left: u16, right: u16) -> Result<Option<i16>> {
+        let format = self.u16(subtable)?;
+        let coverage = subtable + self.u16(subtable + 2)? as usize;
+        let Some(coverage_index) = self.coverage_index(coverage, left)? else {
+            return Ok(None);
+        };
+        let first_format = self.u16(subtable + 4)?;
+        let second_format = self.u16(subtable + 6)?;
+        let first_size = 2 * (first_format & 0xff).count_ones() as usize;
+        let second_size = 2 * (second_format & 0xff).count_ones() as usize;
+        // the x advance of the first glyph is the kerning
+        let x_advance = |record: usize| -> Result<Option<i16>> {
+            if first_format & 0x0004 == 0 {
+                return Ok(Some(0));
+            }
+            let before = 2 * (first_format & 0x0003).count_ones() as usize;
+            Ok(Some(self.i16(record + before)?))
+        };
+        match format {
+            1 => {
+                let pair_sets = self.u16(subtable + 8)? as usize;
+                if coverage_index >= pair_sets {
+                    return Ok(None);
+                }
+                let pair_set = subtable + self.u16(subtable + 10 + 2 * coverage_index)? as usize;
+                let record_size = 2 + first_size + second_size;
+                for i in 0..self.u16(pair_set)? as usize {
+                    let record = pair_set + 2 + record_size * i;
+                    if self.u16(record)? == right {
+                        return x_advance(record + 2);
+                    }
+                }
+                Ok(None)
+            }
+            2 => {
+                let first_classes = subtable + self.u16(subtable + 8)? as usize;
+                let second_classes = subtable + self.u16(subtable + 10)? as usize;
+                let first_class = self.class(first_classes, left)? as usize;
+                let second_class = self.class(second_classes, right)? as usize;
+                let first_class_count = self.u16(subtable + 12)? as usize;
+                let second_class_count = self.u16(subtable + 14)? as usize;
+                if first_class >= first_class_count || second_class >= second_class_count {
+                    return Err("corrupted GPOS table: class out of range".to_string());
+                }
+                let record_size = first_size + second_size;
+                let record =
+                    subtable + 16 + record_size * (first_class * second_class_count + second_class);
+                x_advance(record)
+            }
+            format => Err(format!(
+                "corrupted GPOS table: pair adjustment format {format}"
+            )),
+        }
+    }
+
+    fn coverage_index(&self, coverage: usize, glyph: u16) -> Result<Option<usize>> {
+        match self.u16(coverage)? {
+            1 => {
+                for i in 0..self.u16(coverage + 2)? as usize {
+                    if self.u16(coverage + 4 + 2 * i)? == glyph {
+                        return Ok(Some(i));
+                    }
+                }
+            }
+            2 => {
+                for i in 0..self.u16(coverage + 2)? as usize {
+                    let range = coverage + 4 + 6 * i;
+                    let start = self.u16(range)?;
+                    if (start..=self.u16(range + 2)?).contains(&glyph) {
+                        let start_index = self.u16(range + 4)?;
+                        let index = start_index
+                            .checked_add(glyph - start)
+                            .ok_or("corrupted coverage table: index out of range".to_string())?;
+                        return Ok(Some(index as usize));
+                    }
+                }
+            }
+            format => return Err(format!("corrupted coverage table: format {format}")),
+        }
+        Ok(None)
+    }
+
+    fn class(&self, class_def: usize, glyph: u16) -> Result<u16> {
+        match self.u16(class_def)? {
+            1 => {
+                let start = self.u16(class_def + 2)?;
+                let count = self.u16(class_def + 4)?;
+                if glyph >= start && glyph - start < count {
+                    return self.u16(class_def + 6 + 2 * (glyph - start) as usize);
+                }
+            }
+            2 => {
+                for i in 0..self.u16(class_def + 2)? as usize {
+                    let range = class_def + 4 + 6 * i;
+                    if (self.u16(range)?..=self.u16(range + 2)?).contains(&glyph) {
+                        return self.u16(range + 4);
+                    }
+                }
+            }
+            format => return Err(format!("corrupted class definition: format {format}")),
+        }
+        Ok(0)
+    }
+
+    fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8]> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or("truncated or corrupted font".to_string())
+    }
+
+    fn u8(&self, offset: usize) -> Result<u8> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    fn u16(&self, offset: usize) -> Result<u16> {
+        Ok(u16::from_be_bytes(
+            self.bytes(offset, 2)?.try_into().unwrap(),
+        ))
+    }
+
+    fn i16(&self, offset: usize) -> Result<i16> {
+        Ok(self.u16(offset)? as i16)
+    }
+
+    fn u32(&self, offset: usize) -> Result<u32> {
+        Ok(u32::from_be_bytes(
+            self.bytes(offset, 4)?.try_into().unwrap(),
+        ))
+    }
+}
@@ -0,0 +1,137 @@
+//! Off-screen black and white frame buffers.
+
+use core::convert::Infallible;
+
+use crate::{
+    BWDraw, DisplayError, ErrorType, Mirror, Rotation, TransparencySetting, buffer_size,
+    plane::Plane,
+};
+
+/// Off-screen buffer with the drawing API of the displays, to compose sprites or cache
+/// rendered widgets before drawing them on a display with [`draw_to`](Self::draw_to).
+///
+/// The buffer is owned (`Canvas<[u8; N]>`) or borrowed (`Canvas<&mut [u8]>`) and uses the
+/// [`BWDraw`] layout.
+pub struct Canvas<B> {
+    plane: Plane<B>,
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Canvas<B> {
+    /// # Panics
+    ///
+    /// Panics if `buffer` is not [`buffer_size`]`(width, height)` bytes long.
+    pub fn new(buffer: B, width: u16, height: u16) -> Self {
+        assert_eq!(
+            buffer.as_ref().len(),
+            buffer_size(width, height),
+            "canvas buffer size does not match its resolution"
+        );
+        Canvas {
+            plane: Plane::new(buffer, width, height),
+        }
+    }
+
+    /// Content of the canvas, in its native orientation.
+    pub fn buffer(&self) -> &[u8] {
+        self.plane.buffer()
+    }
+
+    pub fn into_buffer(self) -> B {
+        self.plane.into_buffer()
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.plane.rotation()
+    }
+
+    /// Rotates the drawing coordinates, `width` and `height` are swapped for 90 and 270 degrees.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.plane.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.plane.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.plane.set_mirror(mirror);
+    }
+
+    /// Draws the canvas, in its native orientation, on `display` with its top left corner at
+    /// (`x`, `y`).
+    pub fn draw_to<D: BWDraw + ?Sized>(
+        &self,
+        display: &mut D,
+        x: u16,
+        y: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), D::Error> {
+        let area = self.plane.full_area();
+        display.draw_buffer_with_transparency(self.buffer(), x, y, area.w, area.h, transparency)
+    }
+}
+
+impl<B> ErrorType for Canvas<B> {
+    type Error = DisplayError<Infallible>;
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> BWDraw for Canvas<B> {
+    fn width(&self) -> u16 {
+        self.plane.width()
+    }
+
+    fn height(&self) -> u16 {
+        self.plane.height()
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Self::Error> {
+        self.plane.set_pixel(x, y, color)
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, Self::Error> {
+        self.plane.get_pixel(x, y)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), Self::Error> {
+        self.plane.fill(color);
+        Ok(())
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), Self::Error> {
+        self.plane.fill_rect(x, y, w, h, color)
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.plane.set_buffer(buffer)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), Self::Error> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), Self::Error> {
+        self.plane.draw_buffer(buffer, x, y, w, h, transparency)
+    }
+}
@@ -0,0 +1,161 @@
+//! [`DrawTarget`] implementations to draw on a [`BWDraw`] with the embedded-graphics ecosystem.
+//!
+//! [`BinaryColor::On`] is drawn black and [`BinaryColor::Off`] white.
+
+use embedded_graphics_core::{
+    Pixel,
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Point, Size},
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+};
+
+use crate::BWDraw;
+
+fn to_bw(color: BinaryColor) -> bool {
+    color.is_off()
+}
+
+/// Adapter implementing [`DrawTarget`] for any [`BWDraw`].
+///
+/// Pixels outside of the display are ignored.
+pub struct BWDrawTarget<'a, D: BWDraw> {
+    display: &'a mut D,
+}
+
+impl<'a, D: BWDraw> BWDrawTarget<'a, D> {
+    pub fn new(display: &'a mut D) -> Self {
+        BWDrawTarget { display }
+    }
+
+    /// Fills `len` pixels of a row starting at `start`, which must be on the display.
+    fn fill_run(&mut self, start: Point, len: u32, color: BinaryColor) -> Result<(), D::Error> {
+        self.display
+            .fill_rect(start.x as u16, start.y as u16, len as u16, 1, to_bw(color))
+    }
+}
+
+impl<D: BWDraw> OriginDimensions for BWDrawTarget<'_, D> {
+    fn size(&self) -> Size {
+        Size::new(self.display.width().into(), self.display.height().into())
+    }
+}
+
+impl<D: BWDraw> DrawTarget for BWDrawTarget<'_, D> {
+    type Color = BinaryColor;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounding_box = self.bounding_box();
+        for Pixel(point, color) in pixels {
+            if bounding_box.contains(point) {
+                self.display
+                    .set_pixel(point.x as u16, point.y as u16, to_bw(color))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let visible = area.intersection(&self.bounding_box());
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+
+        // group the colors in horizontal runs so they are written a byte at a time
+        let mut run: Option<(Point, u32, BinaryColor)> = None;
+        for (point, color) in area.points().zip(colors) {
+            if !visible.contains(point) {
+                continue;
+            }
+            if let Some((start, len, run_color)) = &mut run
+                && start.y == point.y
+                && start.x + *len as i32 == point.x
+                && *run_color == color
+            {
+                *len += 1;
+                continue;
+            }
+            if let Some((start, len, run_color)) = run.replace((point, 1, color)) {
+                self.fill_run(start, len, run_color)?;
+            }
+        }
+        if let Some((start, len, run_color)) = run {
+            self.fill_run(start, len, run_color)?;
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let visible = area.intersection(&self.bounding_box());
+        if visible.is_zero_sized() {
+            return Ok(());
+        }
+        self.display.fill_rect(
+            visible.top_left.x as u16,
+            visible.top_left.y as u16,
+            visible.size.width as u16,
+            visible.size.height as u16,
+            to_bw(color),
+        )
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.display.fill(to_bw(color))
+    }
+}
+
+mod framebuffer {
+    use embedded_graphics_core::{
+        Pixel,
+        draw_target::DrawTarget,
+        geometry::{OriginDimensions, Size},
+        pixelcolor::BinaryColor,
+        primitives::Rectangle,
+    };
+
+    use super::BWDrawTarget;
+    use crate::{
+        BWDraw, ErrorType,
+        framebuffer::{FrameBuffer, PanelDriver},
+    };
+
+    impl<P: PanelDriver, const BUFFER_SIZE: usize> OriginDimensions for FrameBuffer<P, BUFFER_SIZE> {
+        fn size(&self) -> Size {
+            Size::new(self.width().into(), self.height().into())
+        }
+    }
+
+    impl<P: PanelDriver, const BUFFER_SIZE: usize> DrawTarget for FrameBuffer<P, BUFFER_SIZE> {
+        type Color = BinaryColor;
+        type Error = <Self as ErrorType>::Error;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            BWDrawTarget::new(self).draw_iter(pixels)
+        }
+
+        fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Self::Color>,
+        {
+            BWDrawTarget::new(self).fill_contiguous(area, colors)
+        }
+
+        fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+            BWDrawTarget::new(self).fill_solid(area, color)
+        }
+
+        fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+            BWDrawTarget::new(self).clear(color)
+        }
+    }
+}
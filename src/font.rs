@@ -0,0 +1,333 @@
+//! Bitmap fonts and text drawing for [`BWDraw`]s.
+//!
+//! The bundled 8x16 font and large digits are rendered from DejaVu Sans Mono, which is
+//! distributed under the Bitstream Vera license reproduced in `LICENSE-DejaVu`.
+
+use crate::{BWDraw, TransparencySetting, plane::draw_chunks};
+
+mod digits_24x40;
+mod font_6x8;
+mod font_8x16;
+
+pub use digits_24x40::DIGITS_24X40;
+pub use font_6x8::FONT_6X8;
+pub use font_8x16::FONT_8X16;
+
+/// Bitmap of a character, cropped to the pixels it covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    /// Index of the first byte of the bitmap in [`Font::bitmaps`].
+    pub offset: u32,
+    pub width: u8,
+    pub height: u8,
+    /// Position of the bitmap relative to the top left corner of the character cell.
+    pub x_offset: i8,
+    pub y_offset: i8,
+    /// Horizontal distance from the start of this character to the start of the next one.
+    pub advance: u8,
+}
+
+/// 1 bit per pixel font covering a contiguous range of characters.
+///
+/// Bitmaps are stored row by row, the most significant bit of a byte is the leftmost pixel and
+/// rows are padded to a whole number of bytes, like the [`BWDraw`] buffers.
+/// Unlike the display buffers, a set bit is a pixel of the character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Font {
+    /// Character of the first glyph, the following glyphs are the following characters.
+    pub first_char: char,
+    /// Distance between two lines of text.
+    pub line_height: u8,
+    /// Distance from the top of a line to the baseline.
+    pub baseline: u8,
+    pub glyphs: &'static [Glyph],
+    pub bitmaps: &'static [u8],
+    /// Spacing adjustments between pairs of characters, sorted by `left` then `right`.
+    pub kerning: &'static [KerningPair],
+}
+
+/// Spacing adjustment applied between `left` and `right` when they follow each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KerningPair {
+    pub left: char,
+    pub right: char,
+    /// Added to the advance of `left`, negative values move `right` closer.
+    pub adjust: i8,
+}
+
+impl Font {
+    /// Returns the glyph of `c`, or `None` if the font does not cover it.
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        let index = (c as u32).checked_sub(self.first_char as u32)?;
+        self.glyphs.get(index as usize)
+    }
+
+    /// Returns the spacing adjustment between `left` and `right`.
+    pub fn kerning(&self, left: char, right: char) -> i8 {
+        self.kerning
+            .binary_search_by(|pair| (pair.left, pair.right).cmp(&(left, right)))
+            .map_or(0, |index| self.kerning[index].adjust)
+    }
+
+    /// Width of a single line of text, characters missing from the font are drawn as `'?'`.
+    pub fn text_width(&self, line: &str) -> u16 {
+        let mut width = 0i32;
+        let mut previous = None;
+        for c in line.chars() {
+            if let Some(glyph) = self.glyph_or_fallback(c) {
+                width += self.kerning_after(previous, c) + glyph.advance as i32;
+                previous = Some(c);
+            }
+        }
+        width.clamp(0, u16::MAX as i32) as u16
+    }
+
+    fn kerning_after(&self, previous: Option<char>, c: char) -> i32 {
+        previous.map_or(0, |previous| self.kerning(previous, c) as i32)
+    }
+
+    fn glyph_or_fallback(&self, c: char) -> Option<&Glyph> {
+        self.glyph(c).or_else(|| self.glyph('?'))
+    }
+}
+
+/// Horizontal alignment of the lines of a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalAlignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Rectangle in which [`draw_text_box`] lays out a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextBox {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+/// Returns the width of the longest line of `text` and the height of all its lines.
+pub fn measure_text(text: &str, font: &Font) -> (u16, u16) {
+    let mut width = 0;
+    let mut lines = 0u16;
+    for line in text.split('\n') {
+        width = width.max(font.text_width(line));
+        lines = lines.saturating_add(1);
+    }
+    (width, lines.saturating_mul(font.line_height as u16))
+}
+
+/// Same as [`measure_text`] once `text` is wrapped to `max_width` like [`draw_text_box`] does.
+pub fn measure_wrapped_text(text: &str, font: &Font, max_width: u16) -> (u16, u16) {
+    let mut width = 0;
+    let mut lines = 0u16;
+    let mut rest = text;
+    loop {
+        let (line, next) = wrap_line(rest, font, max_width);
+        width = width.max(font.text_width(line));
+        lines = lines.saturating_add(1);
+        match next {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+    (width, lines.saturating_mul(font.line_height as u16))
+}
+
+/// Draws `text` with its top left corner at (`x`, `y`), `'\n'` starts a new line.
+///
+/// The parts of the text outside of the display are clipped.
+pub fn draw_text<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: u16,
+    y: u16,
+    text: &str,
+    font: &Font,
+    color: bool,
+) -> Result<(), D::Error> {
+    draw_aligned_text(display, x, y, text, font, color, HorizontalAlignment::Left)
+}
+
+/// Draws `text` with the first line starting at `y`, each line is aligned on `x`: `x` is the
+/// left end, the center or the right end of the lines depending on `alignment`.
+pub fn draw_aligned_text<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: u16,
+    y: u16,
+    text: &str,
+    font: &Font,
+    color: bool,
+    alignment: HorizontalAlignment,
+) -> Result<(), D::Error> {
+    let mut line_y = y as i32;
+    for line in text.split('\n') {
+        let line_x = aligned_x(x as i32, 0, font.text_width(line), alignment);
+        draw_line(display, line_x, line_y, line, font, color)?;
+        line_y += font.line_height as i32;
+    }
+    Ok(())
+}
+
+/// Draws `text` inside `text_box`, wrapping the lines at spaces (or anywhere in words too long
+/// for the box) and `'\n'`. The lines that do not fit in the height of the box are dropped.
+pub fn draw_text_box<D: BWDraw + ?Sized>(
+    display: &mut D,
+    text_box: &TextBox,
+    text: &str,
+    font: &Font,
+    color: bool,
+    alignment: HorizontalAlignment,
+) -> Result<(), D::Error> {
+    let bottom = text_box.y as i32 + text_box.height as i32;
+    let mut line_y = text_box.y as i32;
+    let mut rest = text;
+    while line_y + font.line_height as i32 <= bottom {
+        let (line, next) = wrap_line(rest, font, text_box.width);
+        let line_x = aligned_x(
+            text_box.x as i32,
+            text_box.width,
+            font.text_width(line),
+            alignment,
+        );
+        draw_line(display, line_x, line_y, line, font, color)?;
+        line_y += font.line_height as i32;
+        match next {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+    Ok(())
+}
+
+/// Left end of a `line_width` wide line aligned in the `width` wide span starting at `x`.
+fn aligned_x(x: i32, width: u16, line_width: u16, alignment: HorizontalAlignment) -> i32 {
+    let free = width as i32 - line_width as i32;
+    match alignment {
+        HorizontalAlignment::Left => x,
+        HorizontalAlignment::Center => x + free / 2,
+        HorizontalAlignment::Right => x + free,
+    }
+}
+
+/// Splits the first line to draw from `text`, returns it with the rest of the text if any.
+fn wrap_line<'a>(text: &'a str, font: &Font, max_width: u16) -> (&'a str, Option<&'a str>) {
+    let mut width = 0i32;
+    let mut last_space = None;
+    let mut previous = None;
+    for (i, c) in text.char_indices() {
+        if c == '\n' {
+            return (&text[..i], Some(&text[i + 1..]));
+        }
+        if c == ' ' {
+            last_space = Some(i);
+        }
+        if let Some(glyph) = font.glyph_or_fallback(c) {
+            width += font.kerning_after(previous, c) + glyph.advance as i32;
+            previous = Some(c);
+        }
+        if width > max_width as i32 && c != ' ' {
+            let (line, rest) = match last_space {
+                Some(space) => (&text[..space], &text[space..]),
+                // a line holds at least one character
+                None if i == 0 => text.split_at(c.len_utf8()),
+                None => text.split_at(i),
+            };
+            let rest = rest.trim_start_matches(' ');
+            // a last character alone on its line leaves no line after it
+            return (
+                line.trim_end_matches(' '),
+                (!rest.is_empty()).then_some(rest),
+            );
+        }
+    }
+    (text, None)
+}
+
+/// Draws a line of text whose top left corner is at (`x`, `y`), which may be off the display.
+fn draw_line<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    line: &str,
+    font: &Font,
+    color: bool,
+) -> Result<(), D::Error> {
+    if y >= display.height() as i32 || y + font.line_height as i32 <= 0 {
+        return Ok(());
+    }
+    let mut pen_x = x;
+    let mut previous = None;
+    for c in line.chars() {
+        if let Some(glyph) = font.glyph_or_fallback(c) {
+            pen_x += font.kerning_after(previous, c);
+            if pen_x >= display.width() as i32 {
+                break;
+            }
+            draw_glyph(display, pen_x, y, glyph, font, color)?;
+            pen_x += glyph.advance as i32;
+            previous = Some(c);
+        }
+    }
+    Ok(())
+}
+
+/// Draws the pixels of `glyph` row by row, leaving the other pixels untouched.
+fn draw_glyph<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    glyph: &Glyph,
+    font: &Font,
+    color: bool,
+) -> Result<(), D::Error> {
+    let x = x + glyph.x_offset as i32;
+    let y = y + glyph.y_offset as i32;
+    let stride = glyph.width.div_ceil(8) as usize;
+    let bitmap = &font.bitmaps[glyph.offset as usize..];
+    // the background of the glyph is the transparent color
+    let transparency = if color {
+        TransparencySetting::BlackTransparent
+    } else {
+        TransparencySetting::WhiteTransparent
+    };
+    // columns of the glyph on the display
+    let first = (-x).max(0);
+    let end = (glyph.width as i32)
+        .min(display.width() as i32 - x)
+        .max(first);
+    let columns = first as usize..end as usize;
+    for row in 0..glyph.height as i32 {
+        let row_y = y + row;
+        if row_y < 0 {
+            continue;
+        }
+        if row_y >= display.height() as i32 {
+            break;
+        }
+        let bits = &bitmap[row as usize * stride..][..stride];
+        draw_chunks(
+            columns.clone(),
+            |column| {
+                if bits[column / 8] & (0x80 >> (column % 8)) != 0 {
+                    color
+                } else {
+                    !color
+                }
+            },
+            |chunk, column, len| {
+                display.draw_buffer_with_transparency(
+                    chunk,
+                    (x + column as i32) as u16,
+                    row_y as u16,
+                    len as u16,
+                    1,
+                    transparency,
+                )
+            },
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! 24x40 digits rendered from DejaVu Sans Mono Bold, for clocks and counters.
+//!
+//! Covers `' '..=':'`: the digits and the punctuation used with numbers.
+//!
+//! Bitstream Vera license, see `LICENSE-DejaVu` at the root of the crate.
+
+use super::{Font, Glyph};
+
+pub const DIGITS_24X40: Font = Font {
+    first_char: ' ',
+    line_height: 40,
+    baseline: 35,
+    glyphs: &GLYPHS,
+    bitmaps: &BITMAPS,
+    kerning: &[],
+};
+
+#[rustfmt::skip]
+const GLYPHS: [Glyph; 27] = [
+    Glyph { offset: 0, width: 0, height: 0, x_offset: 0, y_offset: 0, advance: 24 }, // ' '
+    Glyph { offset: 0, width: 6, height: 29, x_offset: 9, y_offset: 6, advance: 24 }, // '!'
+    Glyph { offset: 29, width: 15, height: 11, x_offset: 5, y_offset: 6, advance: 24 }, // '"'
+    Glyph { offset: 51, width: 24, height: 29, x_offset: 0, y_offset: 6, advance: 24 }, // '#'
+    Glyph { offset: 138, width: 18, height: 35, x_offset: 3, y_offset: 5, advance: 24 }, // '$'
+    Glyph { offset: 243, width: 23, height: 28, x_offset: 1, y_offset: 7, advance: 24 }, // '%'
+    Glyph { offset: 327, width: 23, height: 31, x_offset: 1, y_offset: 5, advance: 24 }, // '&'
+    Glyph { offset: 420, width: 5, height: 11, x_offset: 10, y_offset: 6, advance: 24 }, // '\''
+    Glyph { offset: 431, width: 11, height: 35, x_offset: 7, y_offset: 5, advance: 24 }, // '('
+    Glyph { offset: 501, width: 11, height: 35, x_offset: 6, y_offset: 5, advance: 24 }, // ')'
+    Glyph { offset: 571, width: 18, height: 19, x_offset: 3, y_offset: 5, advance: 24 }, // '*'
+    Glyph { offset: 628, width: 22, height: 21, x_offset: 1, y_offset: 12, advance: 24 }, // '+'
+    Glyph { offset: 691, width: 8, height: 12, x_offset: 7, y_offset: 28, advance: 24 }, // ','
+    Glyph { offset: 703, width: 12, height: 5, x_offset: 6, y_offset: 21, advance: 24 }, // '-'
+    Glyph { offset: 713, width: 6, height: 7, x_offset: 9, y_offset: 28, advance: 24 }, // '.'
+    Glyph { offset: 720, width: 19, height: 33, x_offset: 3, y_offset: 6, advance: 24 }, // '/'
+    Glyph { offset: 819, width: 20, height: 31, x_offset: 2, y_offset: 5, advance: 24 }, // '0'
+    Glyph { offset: 912, width: 18, height: 29, x_offset: 4, y_offset: 6, advance: 24 }, // '1'
+    Glyph { offset: 999, width: 19, height: 30, x_offset: 2, y_offset: 5, advance: 24 }, // '2'
+    Glyph { offset: 1089, width: 19, height: 31, x_offset: 2, y_offset: 5, advance: 24 }, // '3'
+    Glyph { offset: 1182, width: 20, height: 29, x_offset: 2, y_offset: 6, advance: 24 }, // '4'
+    Glyph { offset: 1269, width: 18, height: 30, x_offset: 3, y_offset: 6, advance: 24 }, // '5'
+    Glyph { offset: 1359, width: 19, height: 31, x_offset: 3, y_offset: 5, advance: 24 }, // '6'
+    Glyph { offset: 1452, width: 18, height: 29, x_offset: 3, y_offset: 6, advance: 24 }, // '7'
+    Glyph { offset: 1539, width: 19, height: 31, x_offset: 3, y_offset: 5, advance: 24 }, // '8'
+    Glyph { offset: 1632, width: 20, height: 30, x_offset: 2, y_offset: 6, advance: 24 }, // '9'
+    Glyph { offset: 1722, width: 6, height: 21, x_offset: 9, y_offset: 14, advance: 24 }, // ':'
+];
+
+#[rustfmt::skip]
+const BITMAPS: [u8; 1743] = [
+    0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc,
+    0xfc, 0x7c, 0x78, 0x78, 0x78, 0x78, 0x78, 0x78, 0x00, 0x00, 0x00, 0x78,
+    0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8,
+    0x3e, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8, 0x3e, 0xf8,
+    0x3e, 0xf0, 0x3c, 0x00, 0x38, 0x3c, 0x00, 0x7c, 0x7c, 0x00, 0x78, 0x7c,
+    0x00, 0x78, 0x78, 0x00, 0xf8, 0x78, 0x00, 0xf0, 0xf8, 0x00, 0xf0, 0xf8,
+    0x01, 0xf0, 0xf8, 0x3f, 0xff, 0xff, 0x3f, 0xff, 0xff, 0x3f, 0xff, 0xff,
+    0x1f, 0xff, 0xff, 0x03, 0xe1, 0xe0, 0x03, 0xc3, 0xe0, 0x03, 0xc3, 0xe0,
+    0x03, 0xc3, 0xc0, 0x07, 0xc3, 0xc0, 0x07, 0xc7, 0xc0, 0xff, 0xff, 0xfc,
+    0xff, 0xff, 0xfc, 0xff, 0xff, 0xfc, 0xff, 0xff, 0xf8, 0x0f, 0x0f, 0x80,
+    0x0f, 0x0f, 0x00, 0x1f, 0x0f, 0x00, 0x1e, 0x1f, 0x00, 0x1e, 0x1f, 0x00,
+    0x1e, 0x1e, 0x00, 0x3e, 0x1e, 0x00, 0x00, 0xe0, 0x00, 0x00, 0xe0, 0x00,
+    0x00, 0xe0, 0x00, 0x00, 0xe0, 0x00, 0x03, 0xf8, 0x00, 0x1f, 0xff, 0x00,
+    0x3f, 0xff, 0x80, 0x7f, 0xff, 0x80, 0x7f, 0xff, 0x80, 0xfc, 0xe1, 0x80,
+    0xf8, 0xe0, 0x00, 0xf8, 0xe0, 0x00, 0xfc, 0xe0, 0x00, 0xfe, 0xe0, 0x00,
+    0x7f, 0xe0, 0x00, 0x7f, 0xfc, 0x00, 0x3f, 0xff, 0x00, 0x1f, 0xff, 0x80,
+    0x07, 0xff, 0x80, 0x00, 0xff, 0xc0, 0x00, 0xef, 0xc0, 0x00, 0xe7, 0xc0,
+    0x00, 0xe7, 0xc0, 0x00, 0xe7, 0xc0, 0xe0, 0xe7, 0xc0, 0xff, 0xff, 0xc0,
+    0xff, 0xff, 0x80, 0xff, 0xff, 0x00, 0x7f, 0xfe, 0x00, 0x0f, 0xf8, 0x00,
+    0x00, 0xe0, 0x00, 0x00, 0xe0, 0x00, 0x00, 0xe0, 0x00, 0x00, 0xe0, 0x00,
+    0x00, 0xe0, 0x00, 0x1f, 0x00, 0x00, 0x3f, 0xc0, 0x00, 0x7f, 0xe0, 0x00,
+    0xff, 0xe0, 0x00, 0xf0, 0xf0, 0x00, 0xe0, 0xf0, 0x00, 0xe0, 0x70, 0x00,
+    0xe0, 0xf0, 0x00, 0xf1, 0xf0, 0x00, 0x7f, 0xe0, 0x18, 0x7f, 0xc0, 0x7c,
+    0x3f, 0x83, 0xf0, 0x00, 0x0f, 0x80, 0x00, 0x3e, 0x00, 0x01, 0xf0, 0x00,
+    0x07, 0xc0, 0x00, 0x3e, 0x07, 0xf0, 0x78, 0x0f, 0xf8, 0x40, 0x1f, 0xfc,
+    0x00, 0x1e, 0x3c, 0x00, 0x3c, 0x1e, 0x00, 0x3c, 0x1e, 0x00, 0x3c, 0x1e,
+    0x00, 0x1c, 0x1e, 0x00, 0x1f, 0xfc, 0x00, 0x0f, 0xfc, 0x00, 0x0f, 0xf8,
+    0x00, 0x03, 0xe0, 0x00, 0x7c, 0x00, 0x03, 0xff, 0x80, 0x07, 0xff, 0x80,
+    0x0f, 0xff, 0x80, 0x0f, 0xff, 0x80, 0x1f, 0x80, 0x80, 0x1f, 0x80, 0x00,
+    0x1f, 0x80, 0x00, 0x1f, 0x80, 0x00, 0x0f, 0xc0, 0x00, 0x0f, 0xc0, 0x00,
+    0x07, 0xe0, 0x00, 0x07, 0xf0, 0x00, 0x1f, 0xf0, 0x00, 0x3f, 0xf8, 0x00,
+    0x3f, 0xfc, 0x3c, 0x7e, 0xfc, 0x3e, 0xfc, 0x7e, 0x3e, 0xf8, 0x7f, 0x3e,
+    0xf8, 0x3f, 0x3c, 0xf8, 0x1f, 0xbc, 0xf8, 0x1f, 0xfc, 0xf8, 0x0f, 0xfc,
+    0xfc, 0x07, 0xf8, 0xfe, 0x07, 0xf0, 0x7f, 0x0f, 0xf0, 0x7f, 0xff, 0xf8,
+    0x3f, 0xff, 0xf8, 0x1f, 0xff, 0xfc, 0x07, 0xfc, 0x7e, 0x00, 0xe0, 0x00,
+    0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf8, 0xf0, 0x03,
+    0xe0, 0x07, 0xc0, 0x07, 0xc0, 0x0f, 0x80, 0x0f, 0x80, 0x1f, 0x00, 0x1f,
+    0x00, 0x3f, 0x00, 0x3e, 0x00, 0x3e, 0x00, 0x7e, 0x00, 0x7e, 0x00, 0x7c,
+    0x00, 0x7c, 0x00, 0x7c, 0x00, 0x7c, 0x00, 0x7c, 0x00, 0xfc, 0x00, 0x7c,
+    0x00, 0x7c, 0x00, 0x7c, 0x00, 0x7c, 0x00, 0x7c, 0x00, 0x7e, 0x00, 0x7e,
+    0x00, 0x3e, 0x00, 0x3e, 0x00, 0x3f, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x0f,
+    0x80, 0x0f, 0x80, 0x07, 0xc0, 0x07, 0xc0, 0x03, 0xe0, 0xf8, 0x00, 0x7c,
+    0x00, 0x7c, 0x00, 0x3e, 0x00, 0x3e, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x1f,
+    0x80, 0x0f, 0x80, 0x0f, 0x80, 0x0f, 0xc0, 0x07, 0xc0, 0x07, 0xc0, 0x07,
+    0xc0, 0x07, 0xc0, 0x07, 0xe0, 0x07, 0xe0, 0x07, 0xe0, 0x07, 0xe0, 0x07,
+    0xe0, 0x07, 0xc0, 0x07, 0xc0, 0x07, 0xc0, 0x07, 0xc0, 0x0f, 0xc0, 0x0f,
+    0x80, 0x0f, 0x80, 0x1f, 0x80, 0x1f, 0x00, 0x1f, 0x00, 0x3e, 0x00, 0x3e,
+    0x00, 0x7c, 0x00, 0x7c, 0x00, 0xf8, 0x00, 0x00, 0xc0, 0x00, 0x01, 0xe0,
+    0x00, 0x01, 0xe0, 0x00, 0x01, 0xe0, 0x00, 0xe1, 0xe1, 0xc0, 0xf9, 0xe7,
+    0xc0, 0xfd, 0xef, 0xc0, 0x3f, 0xff, 0x00, 0x0f, 0xfc, 0x00, 0x07, 0xf8,
+    0x00, 0x0f, 0xfc, 0x00, 0x3f, 0xff, 0x00, 0xff, 0xff, 0xc0, 0xf9, 0xe7,
+    0xc0, 0xe1, 0xe1, 0xc0, 0x01, 0xe0, 0x00, 0x01, 0xe0, 0x00, 0x01, 0xe0,
+    0x00, 0x01, 0xe0, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78,
+    0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78,
+    0x00, 0x00, 0x78, 0x00, 0xff, 0xff, 0xfc, 0xff, 0xff, 0xfc, 0xff, 0xff,
+    0xfc, 0xff, 0xff, 0xfc, 0x7f, 0xff, 0xf8, 0x00, 0x78, 0x00, 0x00, 0x78,
+    0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78,
+    0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x3f, 0x3f, 0x3f, 0x3f, 0x3f,
+    0x3f, 0x3e, 0x7e, 0x7c, 0x7c, 0x78, 0xf8, 0xff, 0xf0, 0xff, 0xf0, 0xff,
+    0xf0, 0xff, 0xf0, 0xff, 0xf0, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc,
+    0x00, 0x03, 0xe0, 0x00, 0x03, 0xc0, 0x00, 0x07, 0xc0, 0x00, 0x07, 0x80,
+    0x00, 0x0f, 0x80, 0x00, 0x0f, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x1e, 0x00,
+    0x00, 0x1e, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x78, 0x00,
+    0x00, 0x78, 0x00, 0x00, 0xf0, 0x00, 0x00, 0xf0, 0x00, 0x01, 0xf0, 0x00,
+    0x01, 0xe0, 0x00, 0x03, 0xe0, 0x00, 0x03, 0xc0, 0x00, 0x07, 0xc0, 0x00,
+    0x07, 0x80, 0x00, 0x0f, 0x80, 0x00, 0x0f, 0x00, 0x00, 0x0f, 0x00, 0x00,
+    0x1e, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x3c, 0x00, 0x00,
+    0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0xf8, 0x00, 0x00, 0xf0, 0x00, 0x00,
+    0xe0, 0x00, 0x00, 0x00, 0xf0, 0x00, 0x07, 0xfe, 0x00, 0x0f, 0xff, 0x00,
+    0x1f, 0xff, 0x80, 0x1f, 0xff, 0xc0, 0x3f, 0x9f, 0xc0, 0x3f, 0x0f, 0xc0,
+    0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0, 0x7c, 0x03, 0xe0,
+    0x7c, 0x03, 0xe0, 0x7c, 0x03, 0xf0, 0xfc, 0x73, 0xf0, 0xfc, 0xf3, 0xf0,
+    0xfc, 0xf3, 0xf0, 0xfc, 0xf3, 0xf0, 0xfc, 0x63, 0xf0, 0x7c, 0x03, 0xf0,
+    0x7c, 0x03, 0xe0, 0x7c, 0x03, 0xe0, 0x7e, 0x07, 0xe0, 0x7e, 0x07, 0xe0,
+    0x7e, 0x07, 0xe0, 0x3f, 0x0f, 0xc0, 0x3f, 0x9f, 0xc0, 0x1f, 0xff, 0x80,
+    0x1f, 0xff, 0x80, 0x0f, 0xff, 0x00, 0x03, 0xfc, 0x00, 0x00, 0x60, 0x00,
+    0x1f, 0xf0, 0x00, 0xff, 0xf0, 0x00, 0xff, 0xf0, 0x00, 0xff, 0xf0, 0x00,
+    0xff, 0xf0, 0x00, 0xf3, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0,
+    0xff, 0xff, 0xc0, 0x03, 0xe0, 0x00, 0x3f, 0xfe, 0x00, 0x7f, 0xff, 0x00,
+    0x7f, 0xff, 0x80, 0x7f, 0xff, 0xc0, 0x78, 0x1f, 0xc0, 0x40, 0x0f, 0xe0,
+    0x00, 0x07, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x07, 0xc0,
+    0x00, 0x0f, 0xc0, 0x00, 0x0f, 0xc0, 0x00, 0x1f, 0x80, 0x00, 0x3f, 0x00,
+    0x00, 0x7f, 0x00, 0x00, 0xfe, 0x00, 0x01, 0xfc, 0x00, 0x03, 0xf8, 0x00,
+    0x07, 0xf0, 0x00, 0x07, 0xe0, 0x00, 0x0f, 0xc0, 0x00, 0x1f, 0x80, 0x00,
+    0x3f, 0x00, 0x00, 0x7e, 0x00, 0x00, 0xff, 0xff, 0xe0, 0xff, 0xff, 0xe0,
+    0xff, 0xff, 0xe0, 0xff, 0xff, 0xe0, 0xff, 0xff, 0xe0, 0x03, 0xf0, 0x00,
+    0x3f, 0xfe, 0x00, 0x7f, 0xff, 0x00, 0x7f, 0xff, 0x80, 0x7f, 0xff, 0xc0,
+    0x7c, 0x1f, 0xc0, 0x00, 0x0f, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x07, 0xe0,
+    0x00, 0x07, 0xe0, 0x00, 0x07, 0xc0, 0x00, 0x1f, 0xc0, 0x03, 0xff, 0x80,
+    0x03, 0xfe, 0x00, 0x03, 0xfc, 0x00, 0x03, 0xff, 0x00, 0x03, 0xff, 0xc0,
+    0x00, 0x0f, 0xc0, 0x00, 0x07, 0xe0, 0x00, 0x03, 0xe0, 0x00, 0x03, 0xe0,
+    0x00, 0x03, 0xe0, 0x00, 0x03, 0xe0, 0x00, 0x03, 0xe0, 0xc0, 0x07, 0xe0,
+    0xfc, 0x3f, 0xe0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0x80,
+    0x3f, 0xfe, 0x00, 0x01, 0xc0, 0x00, 0x00, 0x1f, 0x80, 0x00, 0x3f, 0x80,
+    0x00, 0x7f, 0x80, 0x00, 0x7f, 0x80, 0x00, 0xff, 0x80, 0x01, 0xff, 0x80,
+    0x01, 0xff, 0x80, 0x03, 0xef, 0x80, 0x03, 0xcf, 0x80, 0x07, 0x8f, 0x80,
+    0x0f, 0x8f, 0x80, 0x0f, 0x0f, 0x80, 0x1f, 0x0f, 0x80, 0x3e, 0x0f, 0x80,
+    0x3c, 0x0f, 0x80, 0x7c, 0x0f, 0x80, 0xf8, 0x0f, 0x80, 0xf8, 0x1f, 0x80,
+    0xff, 0xff, 0xf0, 0xff, 0xff, 0xf0, 0xff, 0xff, 0xf0, 0xff, 0xff, 0xf0,
+    0xff, 0xff, 0xf0, 0x00, 0x0f, 0x80, 0x00, 0x0f, 0x80, 0x00, 0x0f, 0x80,
+    0x00, 0x0f, 0x80, 0x00, 0x0f, 0x80, 0x00, 0x0f, 0x80, 0x7f, 0xff, 0x80,
+    0x7f, 0xff, 0x80, 0x7f, 0xff, 0x80, 0x7f, 0xff, 0x80, 0x7f, 0xff, 0x80,
+    0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00, 0x78, 0x00, 0x00,
+    0x78, 0x00, 0x00, 0x7f, 0xf8, 0x00, 0x7f, 0xfc, 0x00, 0x7f, 0xff, 0x00,
+    0x7f, 0xff, 0x00, 0x7f, 0xff, 0x80, 0x40, 0x1f, 0xc0, 0x00, 0x0f, 0xc0,
+    0x00, 0x0f, 0xc0, 0x00, 0x07, 0xc0, 0x00, 0x07, 0xc0, 0x00, 0x07, 0xc0,
+    0x00, 0x0f, 0xc0, 0x00, 0x0f, 0xc0, 0x80, 0x1f, 0xc0, 0xf0, 0x7f, 0x80,
+    0xff, 0xff, 0x80, 0xff, 0xff, 0x00, 0xff, 0xfe, 0x00, 0xff, 0xf8, 0x00,
+    0x03, 0x80, 0x00, 0x00, 0x78, 0x00, 0x03, 0xff, 0x80, 0x0f, 0xff, 0x80,
+    0x1f, 0xff, 0x80, 0x3f, 0xff, 0x80, 0x3f, 0x83, 0x80, 0x7e, 0x00, 0x00,
+    0x7c, 0x00, 0x00, 0xfc, 0x00, 0x00, 0xf8, 0x00, 0x00, 0xf8, 0x00, 0x00,
+    0xf9, 0xfc, 0x00, 0xfb, 0xff, 0x00, 0xff, 0xff, 0x80, 0xff, 0xff, 0xc0,
+    0xff, 0x9f, 0xc0, 0xfe, 0x0f, 0xc0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0,
+    0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0,
+    0xfc, 0x07, 0xe0, 0x7e, 0x07, 0xc0, 0x7f, 0x1f, 0xc0, 0x3f, 0xff, 0xc0,
+    0x3f, 0xff, 0x80, 0x1f, 0xff, 0x00, 0x07, 0xfe, 0x00, 0x00, 0xe0, 0x00,
+    0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0, 0xff, 0xff, 0xc0,
+    0xff, 0xff, 0xc0, 0x00, 0x0f, 0x80, 0x00, 0x1f, 0x80, 0x00, 0x1f, 0x80,
+    0x00, 0x3f, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x7e, 0x00,
+    0x00, 0x7e, 0x00, 0x00, 0xfc, 0x00, 0x00, 0xfc, 0x00, 0x00, 0xfc, 0x00,
+    0x01, 0xf8, 0x00, 0x01, 0xf8, 0x00, 0x03, 0xf0, 0x00, 0x03, 0xf0, 0x00,
+    0x03, 0xf0, 0x00, 0x07, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x0f, 0xc0, 0x00,
+    0x0f, 0xc0, 0x00, 0x0f, 0xc0, 0x00, 0x1f, 0x80, 0x00, 0x1f, 0x80, 0x00,
+    0x1f, 0x00, 0x00, 0x01, 0xe0, 0x00, 0x0f, 0xfc, 0x00, 0x1f, 0xff, 0x00,
+    0x3f, 0xff, 0x00, 0x7f, 0xff, 0x80, 0x7e, 0x1f, 0x80, 0xfc, 0x0f, 0xc0,
+    0xf8, 0x07, 0xc0, 0xf8, 0x07, 0xc0, 0xf8, 0x07, 0xc0, 0x7c, 0x0f, 0xc0,
+    0x7e, 0x0f, 0x80, 0x3f, 0xff, 0x00, 0x1f, 0xfe, 0x00, 0x0f, 0xfc, 0x00,
+    0x1f, 0xfe, 0x00, 0x7f, 0xff, 0x80, 0x7e, 0x1f, 0x80, 0xfc, 0x0f, 0xc0,
+    0xf8, 0x07, 0xc0, 0xf8, 0x07, 0xc0, 0xf8, 0x07, 0xe0, 0xf8, 0x07, 0xe0,
+    0xf8, 0x07, 0xc0, 0xfc, 0x0f, 0xc0, 0xfe, 0x1f, 0xc0, 0x7f, 0xff, 0x80,
+    0x7f, 0xff, 0x80, 0x3f, 0xff, 0x00, 0x0f, 0xfc, 0x00, 0x00, 0xc0, 0x00,
+    0x07, 0xfc, 0x00, 0x1f, 0xff, 0x00, 0x3f, 0xff, 0x80, 0x3f, 0xff, 0x80,
+    0x7f, 0x0f, 0xc0, 0x7e, 0x0f, 0xc0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0,
+    0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0, 0xfc, 0x07, 0xe0,
+    0xfc, 0x07, 0xe0, 0x7e, 0x0f, 0xf0, 0x7f, 0xbf, 0xf0, 0x7f, 0xff, 0xf0,
+    0x3f, 0xff, 0xe0, 0x1f, 0xff, 0xe0, 0x0f, 0xf3, 0xe0, 0x00, 0x03, 0xe0,
+    0x00, 0x03, 0xe0, 0x00, 0x07, 0xe0, 0x00, 0x07, 0xc0, 0x00, 0x0f, 0xc0,
+    0x30, 0x1f, 0xc0, 0x3f, 0xff, 0x80, 0x3f, 0xff, 0x00, 0x3f, 0xfe, 0x00,
+    0x3f, 0xfc, 0x00, 0x07, 0xe0, 0x00, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc, 0xfc,
+    0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfc, 0xfc, 0xfc, 0xfc,
+    0xfc, 0xfc, 0xfc,
+];
@@ -0,0 +1,163 @@
+//! 6x8 font, 5x7 glyphs in a 6x8 cell.
+
+use super::{Font, Glyph};
+
+pub const FONT_6X8: Font = Font {
+    first_char: ' ',
+    line_height: 8,
+    baseline: 7,
+    glyphs: &GLYPHS,
+    bitmaps: &BITMAPS,
+    kerning: &[],
+};
+
+#[rustfmt::skip]
+const GLYPHS: [Glyph; 95] = [
+    Glyph { offset: 0, width: 0, height: 0, x_offset: 0, y_offset: 0, advance: 6 }, // ' '
+    Glyph { offset: 0, width: 1, height: 7, x_offset: 2, y_offset: 0, advance: 6 }, // '!'
+    Glyph { offset: 7, width: 3, height: 3, x_offset: 1, y_offset: 0, advance: 6 }, // '"'
+    Glyph { offset: 10, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '#'
+    Glyph { offset: 17, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '$'
+    Glyph { offset: 24, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '%'
+    Glyph { offset: 31, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '&'
+    Glyph { offset: 38, width: 2, height: 3, x_offset: 1, y_offset: 0, advance: 6 }, // '\''
+    Glyph { offset: 41, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // '('
+    Glyph { offset: 48, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // ')'
+    Glyph { offset: 55, width: 5, height: 5, x_offset: 0, y_offset: 1, advance: 6 }, // '*'
+    Glyph { offset: 60, width: 5, height: 5, x_offset: 0, y_offset: 1, advance: 6 }, // '+'
+    Glyph { offset: 65, width: 2, height: 3, x_offset: 1, y_offset: 4, advance: 6 }, // ','
+    Glyph { offset: 68, width: 5, height: 1, x_offset: 0, y_offset: 3, advance: 6 }, // '-'
+    Glyph { offset: 69, width: 2, height: 2, x_offset: 1, y_offset: 5, advance: 6 }, // '.'
+    Glyph { offset: 71, width: 5, height: 5, x_offset: 0, y_offset: 1, advance: 6 }, // '/'
+    Glyph { offset: 76, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '0'
+    Glyph { offset: 83, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // '1'
+    Glyph { offset: 90, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '2'
+    Glyph { offset: 97, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '3'
+    Glyph { offset: 104, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '4'
+    Glyph { offset: 111, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '5'
+    Glyph { offset: 118, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '6'
+    Glyph { offset: 125, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '7'
+    Glyph { offset: 132, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '8'
+    Glyph { offset: 139, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '9'
+    Glyph { offset: 146, width: 2, height: 5, x_offset: 1, y_offset: 1, advance: 6 }, // ':'
+    Glyph { offset: 151, width: 2, height: 6, x_offset: 1, y_offset: 1, advance: 6 }, // ';'
+    Glyph { offset: 157, width: 4, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '<'
+    Glyph { offset: 164, width: 5, height: 3, x_offset: 0, y_offset: 2, advance: 6 }, // '='
+    Glyph { offset: 167, width: 4, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // '>'
+    Glyph { offset: 174, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '?'
+    Glyph { offset: 181, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // '@'
+    Glyph { offset: 188, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'A'
+    Glyph { offset: 195, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'B'
+    Glyph { offset: 202, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'C'
+    Glyph { offset: 209, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'D'
+    Glyph { offset: 216, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'E'
+    Glyph { offset: 223, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'F'
+    Glyph { offset: 230, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'G'
+    Glyph { offset: 237, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'H'
+    Glyph { offset: 244, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // 'I'
+    Glyph { offset: 251, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'J'
+    Glyph { offset: 258, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'K'
+    Glyph { offset: 265, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'L'
+    Glyph { offset: 272, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'M'
+    Glyph { offset: 279, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'N'
+    Glyph { offset: 286, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'O'
+    Glyph { offset: 293, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'P'
+    Glyph { offset: 300, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'Q'
+    Glyph { offset: 307, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'R'
+    Glyph { offset: 314, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'S'
+    Glyph { offset: 321, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'T'
+    Glyph { offset: 328, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'U'
+    Glyph { offset: 335, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'V'
+    Glyph { offset: 342, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'W'
+    Glyph { offset: 349, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'X'
+    Glyph { offset: 356, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'Y'
+    Glyph { offset: 363, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'Z'
+    Glyph { offset: 370, width: 3, height: 7, x_offset: 2, y_offset: 0, advance: 6 }, // '['
+    Glyph { offset: 377, width: 5, height: 5, x_offset: 0, y_offset: 1, advance: 6 }, // '\\'
+    Glyph { offset: 382, width: 3, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // ']'
+    Glyph { offset: 389, width: 5, height: 3, x_offset: 0, y_offset: 0, advance: 6 }, // '^'
+    Glyph { offset: 392, width: 5, height: 1, x_offset: 0, y_offset: 6, advance: 6 }, // '_'
+    Glyph { offset: 393, width: 3, height: 3, x_offset: 1, y_offset: 0, advance: 6 }, // '`'
+    Glyph { offset: 396, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'a'
+    Glyph { offset: 401, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'b'
+    Glyph { offset: 408, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'c'
+    Glyph { offset: 413, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'd'
+    Glyph { offset: 420, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'e'
+    Glyph { offset: 425, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'f'
+    Glyph { offset: 432, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'g'
+    Glyph { offset: 437, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'h'
+    Glyph { offset: 444, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // 'i'
+    Glyph { offset: 451, width: 4, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 'j'
+    Glyph { offset: 458, width: 4, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // 'k'
+    Glyph { offset: 465, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // 'l'
+    Glyph { offset: 472, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'm'
+    Glyph { offset: 477, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'n'
+    Glyph { offset: 482, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'o'
+    Glyph { offset: 487, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'p'
+    Glyph { offset: 492, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'q'
+    Glyph { offset: 497, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'r'
+    Glyph { offset: 502, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 's'
+    Glyph { offset: 507, width: 5, height: 7, x_offset: 0, y_offset: 0, advance: 6 }, // 't'
+    Glyph { offset: 514, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'u'
+    Glyph { offset: 519, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'v'
+    Glyph { offset: 524, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'w'
+    Glyph { offset: 529, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'x'
+    Glyph { offset: 534, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'y'
+    Glyph { offset: 539, width: 5, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'z'
+    Glyph { offset: 544, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // '{'
+    Glyph { offset: 551, width: 1, height: 7, x_offset: 2, y_offset: 0, advance: 6 }, // '|'
+    Glyph { offset: 558, width: 3, height: 7, x_offset: 1, y_offset: 0, advance: 6 }, // '}'
+    Glyph { offset: 565, width: 5, height: 3, x_offset: 0, y_offset: 0, advance: 6 }, // '~'
+];
+
+#[rustfmt::skip]
+const BITMAPS: [u8; 568] = [
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x00, 0x80, 0xa0, 0xa0, 0xa0, 0x50, 0x50,
+    0xf8, 0x50, 0xf8, 0x50, 0x50, 0x20, 0x78, 0xa0, 0x70, 0x28, 0xf0, 0x20,
+    0xc0, 0xc8, 0x10, 0x20, 0x40, 0x98, 0x18, 0x60, 0x90, 0xa0, 0x40, 0xa8,
+    0x90, 0x68, 0xc0, 0x40, 0x80, 0x20, 0x40, 0x80, 0x80, 0x80, 0x40, 0x20,
+    0x80, 0x40, 0x20, 0x20, 0x20, 0x40, 0x80, 0x50, 0x20, 0xf8, 0x20, 0x50,
+    0x20, 0x20, 0xf8, 0x20, 0x20, 0xc0, 0x40, 0x80, 0xf8, 0xc0, 0xc0, 0x08,
+    0x10, 0x20, 0x40, 0x80, 0x70, 0x88, 0x98, 0xa8, 0xc8, 0x88, 0x70, 0x40,
+    0xc0, 0x40, 0x40, 0x40, 0x40, 0xe0, 0x70, 0x88, 0x08, 0x10, 0x20, 0x40,
+    0xf8, 0xf8, 0x10, 0x20, 0x10, 0x08, 0x88, 0x70, 0x10, 0x30, 0x50, 0x90,
+    0xf8, 0x10, 0x10, 0xf8, 0x80, 0xf0, 0x08, 0x08, 0x88, 0x70, 0x30, 0x40,
+    0x80, 0xf0, 0x88, 0x88, 0x70, 0xf8, 0x08, 0x10, 0x20, 0x40, 0x40, 0x40,
+    0x70, 0x88, 0x88, 0x70, 0x88, 0x88, 0x70, 0x70, 0x88, 0x88, 0x78, 0x08,
+    0x10, 0x60, 0xc0, 0xc0, 0x00, 0xc0, 0xc0, 0xc0, 0xc0, 0x00, 0xc0, 0x40,
+    0x80, 0x10, 0x20, 0x40, 0x80, 0x40, 0x20, 0x10, 0xf8, 0x00, 0xf8, 0x80,
+    0x40, 0x20, 0x10, 0x20, 0x40, 0x80, 0x70, 0x88, 0x08, 0x10, 0x20, 0x00,
+    0x20, 0x70, 0x88, 0x08, 0x68, 0xa8, 0xa8, 0x70, 0x70, 0x88, 0x88, 0x88,
+    0xf8, 0x88, 0x88, 0xf0, 0x88, 0x88, 0xf0, 0x88, 0x88, 0xf0, 0x70, 0x88,
+    0x80, 0x80, 0x80, 0x88, 0x70, 0xe0, 0x90, 0x88, 0x88, 0x88, 0x90, 0xe0,
+    0xf8, 0x80, 0x80, 0xf0, 0x80, 0x80, 0xf8, 0xf8, 0x80, 0x80, 0xe0, 0x80,
+    0x80, 0x80, 0x70, 0x88, 0x80, 0x80, 0x98, 0x88, 0x70, 0x88, 0x88, 0x88,
+    0xf8, 0x88, 0x88, 0x88, 0xe0, 0x40, 0x40, 0x40, 0x40, 0x40, 0xe0, 0x38,
+    0x10, 0x10, 0x10, 0x10, 0x90, 0x60, 0x88, 0x90, 0xa0, 0xc0, 0xa0, 0x90,
+    0x88, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xf8, 0x88, 0xd8, 0xa8, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x88, 0xc8, 0xa8, 0x98, 0x88, 0x88, 0x70, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x70, 0xf0, 0x88, 0x88, 0xf0, 0x80, 0x80, 0x80,
+    0x70, 0x88, 0x88, 0x88, 0xa8, 0x90, 0x68, 0xf0, 0x88, 0x88, 0xf0, 0xa0,
+    0x90, 0x88, 0x78, 0x80, 0x80, 0x70, 0x08, 0x08, 0xf0, 0xf8, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x88, 0x88, 0x88, 0x88, 0x88, 0x88, 0x70, 0x88,
+    0x88, 0x88, 0x88, 0x88, 0x50, 0x20, 0x88, 0x88, 0x88, 0xa8, 0xa8, 0xd8,
+    0x88, 0x88, 0x88, 0x50, 0x20, 0x50, 0x88, 0x88, 0x88, 0x88, 0x50, 0x20,
+    0x20, 0x20, 0x20, 0xf8, 0x08, 0x10, 0x20, 0x40, 0x80, 0xf8, 0xe0, 0x80,
+    0x80, 0x80, 0x80, 0x80, 0xe0, 0x80, 0x40, 0x20, 0x10, 0x08, 0xe0, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0xe0, 0x20, 0x50, 0x88, 0xf8, 0x80, 0x40, 0x20,
+    0x70, 0x08, 0x78, 0x88, 0x78, 0x80, 0x80, 0xb0, 0xc8, 0x88, 0x88, 0xf0,
+    0x70, 0x80, 0x80, 0x88, 0x70, 0x08, 0x08, 0x68, 0x98, 0x88, 0x88, 0x78,
+    0x70, 0x88, 0xf8, 0x80, 0x70, 0x30, 0x48, 0x40, 0xe0, 0x40, 0x40, 0x40,
+    0x78, 0x88, 0x78, 0x08, 0x30, 0x80, 0x80, 0xb0, 0xc8, 0x88, 0x88, 0x88,
+    0x40, 0x00, 0xc0, 0x40, 0x40, 0x40, 0xe0, 0x10, 0x00, 0x30, 0x10, 0x10,
+    0x90, 0x60, 0x80, 0x80, 0x90, 0xa0, 0xc0, 0xa0, 0x90, 0xc0, 0x40, 0x40,
+    0x40, 0x40, 0x40, 0xe0, 0xd0, 0xa8, 0xa8, 0x88, 0x88, 0xb0, 0xc8, 0x88,
+    0x88, 0x88, 0x70, 0x88, 0x88, 0x88, 0x70, 0xf0, 0x88, 0xf0, 0x80, 0x80,
+    0x68, 0x98, 0x78, 0x08, 0x08, 0xb0, 0xc8, 0x80, 0x80, 0x80, 0x70, 0x80,
+    0x70, 0x08, 0xf0, 0x40, 0x40, 0xe0, 0x40, 0x40, 0x48, 0x30, 0x88, 0x88,
+    0x88, 0x98, 0x68, 0x88, 0x88, 0x88, 0x50, 0x20, 0x88, 0x88, 0xa8, 0xa8,
+    0x50, 0x88, 0x50, 0x20, 0x50, 0x88, 0x88, 0x88, 0x78, 0x08, 0x70, 0xf8,
+    0x10, 0x20, 0x40, 0xf8, 0x20, 0x40, 0x40, 0x80, 0x40, 0x40, 0x20, 0x80,
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x40, 0x40, 0x20, 0x40, 0x40,
+    0x80, 0x40, 0xa8, 0x10,
+];
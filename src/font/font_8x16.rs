@@ -0,0 +1,190 @@
+//! 8x16 font rendered from DejaVu Sans Mono.
+//!
+//! Bitstream Vera license, see `LICENSE-DejaVu` at the root of the crate.
+
+use super::{Font, Glyph};
+
+pub const FONT_8X16: Font = Font {
+    first_char: ' ',
+    line_height: 16,
+    baseline: 13,
+    glyphs: &GLYPHS,
+    bitmaps: &BITMAPS,
+    kerning: &[],
+};
+
+#[rustfmt::skip]
+const GLYPHS: [Glyph; 95] = [
+    Glyph { offset: 0, width: 0, height: 0, x_offset: 0, y_offset: 0, advance: 8 }, // ' '
+    Glyph { offset: 0, width: 2, height: 10, x_offset: 3, y_offset: 3, advance: 8 }, // '!'
+    Glyph { offset: 10, width: 4, height: 4, x_offset: 2, y_offset: 3, advance: 8 }, // '"'
+    Glyph { offset: 14, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // '#'
+    Glyph { offset: 24, width: 7, height: 12, x_offset: 1, y_offset: 3, advance: 8 }, // '$'
+    Glyph { offset: 36, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // '%'
+    Glyph { offset: 46, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // '&'
+    Glyph { offset: 56, width: 2, height: 3, x_offset: 3, y_offset: 3, advance: 8 }, // '\''
+    Glyph { offset: 59, width: 3, height: 12, x_offset: 3, y_offset: 3, advance: 8 }, // '('
+    Glyph { offset: 71, width: 3, height: 12, x_offset: 2, y_offset: 3, advance: 8 }, // ')'
+    Glyph { offset: 83, width: 6, height: 6, x_offset: 1, y_offset: 3, advance: 8 }, // '*'
+    Glyph { offset: 89, width: 8, height: 7, x_offset: 0, y_offset: 5, advance: 8 }, // '+'
+    Glyph { offset: 96, width: 2, height: 4, x_offset: 3, y_offset: 11, advance: 8 }, // ','
+    Glyph { offset: 100, width: 4, height: 2, x_offset: 2, y_offset: 8, advance: 8 }, // '-'
+    Glyph { offset: 102, width: 2, height: 2, x_offset: 3, y_offset: 11, advance: 8 }, // '.'
+    Glyph { offset: 104, width: 6, height: 11, x_offset: 1, y_offset: 3, advance: 8 }, // '/'
+    Glyph { offset: 115, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // '0'
+    Glyph { offset: 125, width: 5, height: 10, x_offset: 2, y_offset: 3, advance: 8 }, // '1'
+    Glyph { offset: 135, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // '2'
+    Glyph { offset: 146, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // '3'
+    Glyph { offset: 157, width: 7, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // '4'
+    Glyph { offset: 167, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // '5'
+    Glyph { offset: 177, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // '6'
+    Glyph { offset: 188, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // '7'
+    Glyph { offset: 198, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // '8'
+    Glyph { offset: 208, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // '9'
+    Glyph { offset: 218, width: 2, height: 7, x_offset: 3, y_offset: 6, advance: 8 }, // ':'
+    Glyph { offset: 225, width: 2, height: 9, x_offset: 3, y_offset: 6, advance: 8 }, // ';'
+    Glyph { offset: 234, width: 8, height: 7, x_offset: 0, y_offset: 5, advance: 8 }, // '<'
+    Glyph { offset: 241, width: 7, height: 4, x_offset: 1, y_offset: 7, advance: 8 }, // '='
+    Glyph { offset: 245, width: 8, height: 6, x_offset: 0, y_offset: 6, advance: 8 }, // '>'
+    Glyph { offset: 251, width: 5, height: 10, x_offset: 2, y_offset: 3, advance: 8 }, // '?'
+    Glyph { offset: 261, width: 8, height: 12, x_offset: 0, y_offset: 3, advance: 8 }, // '@'
+    Glyph { offset: 273, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'A'
+    Glyph { offset: 283, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'B'
+    Glyph { offset: 293, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'C'
+    Glyph { offset: 303, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'D'
+    Glyph { offset: 313, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'E'
+    Glyph { offset: 323, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'F'
+    Glyph { offset: 333, width: 7, height: 11, x_offset: 0, y_offset: 2, advance: 8 }, // 'G'
+    Glyph { offset: 344, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'H'
+    Glyph { offset: 354, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'I'
+    Glyph { offset: 364, width: 6, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'J'
+    Glyph { offset: 374, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'K'
+    Glyph { offset: 384, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'L'
+    Glyph { offset: 394, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'M'
+    Glyph { offset: 404, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'N'
+    Glyph { offset: 414, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'O'
+    Glyph { offset: 424, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'P'
+    Glyph { offset: 434, width: 8, height: 11, x_offset: 0, y_offset: 3, advance: 8 }, // 'Q'
+    Glyph { offset: 445, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'R'
+    Glyph { offset: 455, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'S'
+    Glyph { offset: 465, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'T'
+    Glyph { offset: 475, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'U'
+    Glyph { offset: 485, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'V'
+    Glyph { offset: 495, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'W'
+    Glyph { offset: 505, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'X'
+    Glyph { offset: 515, width: 8, height: 10, x_offset: 0, y_offset: 3, advance: 8 }, // 'Y'
+    Glyph { offset: 525, width: 7, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 'Z'
+    Glyph { offset: 535, width: 3, height: 13, x_offset: 3, y_offset: 2, advance: 8 }, // '['
+    Glyph { offset: 548, width: 6, height: 11, x_offset: 1, y_offset: 3, advance: 8 }, // '\\'
+    Glyph { offset: 559, width: 3, height: 13, x_offset: 2, y_offset: 2, advance: 8 }, // ']'
+    Glyph { offset: 572, width: 6, height: 4, x_offset: 1, y_offset: 3, advance: 8 }, // '^'
+    Glyph { offset: 576, width: 0, height: 0, x_offset: 0, y_offset: 0, advance: 8 }, // '_'
+    Glyph { offset: 576, width: 2, height: 2, x_offset: 2, y_offset: 2, advance: 8 }, // '`'
+    Glyph { offset: 578, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'a'
+    Glyph { offset: 586, width: 7, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'b'
+    Glyph { offset: 597, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'c'
+    Glyph { offset: 605, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'd'
+    Glyph { offset: 616, width: 7, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'e'
+    Glyph { offset: 624, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'f'
+    Glyph { offset: 635, width: 6, height: 11, x_offset: 1, y_offset: 5, advance: 8 }, // 'g'
+    Glyph { offset: 646, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'h'
+    Glyph { offset: 657, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'i'
+    Glyph { offset: 668, width: 4, height: 14, x_offset: 1, y_offset: 2, advance: 8 }, // 'j'
+    Glyph { offset: 682, width: 7, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'k'
+    Glyph { offset: 693, width: 6, height: 11, x_offset: 1, y_offset: 2, advance: 8 }, // 'l'
+    Glyph { offset: 704, width: 8, height: 8, x_offset: 0, y_offset: 5, advance: 8 }, // 'm'
+    Glyph { offset: 712, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'n'
+    Glyph { offset: 720, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'o'
+    Glyph { offset: 728, width: 6, height: 11, x_offset: 1, y_offset: 5, advance: 8 }, // 'p'
+    Glyph { offset: 739, width: 6, height: 11, x_offset: 1, y_offset: 5, advance: 8 }, // 'q'
+    Glyph { offset: 750, width: 6, height: 8, x_offset: 2, y_offset: 5, advance: 8 }, // 'r'
+    Glyph { offset: 758, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 's'
+    Glyph { offset: 766, width: 6, height: 10, x_offset: 1, y_offset: 3, advance: 8 }, // 't'
+    Glyph { offset: 776, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'u'
+    Glyph { offset: 784, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'v'
+    Glyph { offset: 792, width: 8, height: 8, x_offset: 0, y_offset: 5, advance: 8 }, // 'w'
+    Glyph { offset: 800, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'x'
+    Glyph { offset: 808, width: 7, height: 11, x_offset: 1, y_offset: 5, advance: 8 }, // 'y'
+    Glyph { offset: 819, width: 6, height: 8, x_offset: 1, y_offset: 5, advance: 8 }, // 'z'
+    Glyph { offset: 827, width: 6, height: 13, x_offset: 1, y_offset: 2, advance: 8 }, // '{'
+    Glyph { offset: 840, width: 2, height: 13, x_offset: 3, y_offset: 3, advance: 8 }, // '|'
+    Glyph { offset: 853, width: 6, height: 13, x_offset: 1, y_offset: 2, advance: 8 }, // '}'
+    Glyph { offset: 866, width: 8, height: 2, x_offset: 0, y_offset: 8, advance: 8 }, // '~'
+];
+
+#[rustfmt::skip]
+const BITMAPS: [u8; 868] = [
+    0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0x00, 0x00, 0xc0, 0xc0, 0x90, 0x90,
+    0x90, 0x90, 0x12, 0x12, 0x36, 0x7f, 0x24, 0x24, 0xff, 0x6c, 0x48, 0x48,
+    0x10, 0x7c, 0xd0, 0x90, 0xf0, 0x78, 0x1c, 0x16, 0x94, 0xf8, 0x10, 0x10,
+    0x60, 0xd0, 0x98, 0xd1, 0x6e, 0x30, 0x4f, 0x09, 0x09, 0x0e, 0x3c, 0x60,
+    0x60, 0x20, 0x70, 0xd9, 0xcd, 0xc7, 0x46, 0x7f, 0xc0, 0xc0, 0xc0, 0x40,
+    0x40, 0xc0, 0x80, 0x80, 0x80, 0x80, 0x80, 0xc0, 0xc0, 0x40, 0x60, 0x40,
+    0x40, 0x60, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x60, 0x40, 0xc0, 0x30,
+    0xfc, 0x30, 0x78, 0xb4, 0x30, 0x18, 0x18, 0x18, 0xff, 0x18, 0x18, 0x18,
+    0xc0, 0xc0, 0x80, 0x80, 0x60, 0xf0, 0xc0, 0xc0, 0x0c, 0x08, 0x08, 0x18,
+    0x10, 0x30, 0x20, 0x60, 0x40, 0xc0, 0x80, 0x78, 0xcc, 0x84, 0x84, 0xb4,
+    0xb4, 0x84, 0x84, 0xcc, 0x78, 0xe0, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x70, 0xf8, 0x20, 0xf8, 0x0c, 0x0c, 0x0c, 0x08, 0x18, 0x30, 0x60,
+    0xc0, 0xfc, 0x20, 0xf8, 0x0c, 0x0c, 0x0c, 0x78, 0x0c, 0x04, 0x04, 0x0c,
+    0xf8, 0x0c, 0x1c, 0x14, 0x24, 0x24, 0x44, 0xfe, 0x7e, 0x04, 0x04, 0xfc,
+    0xc0, 0xc0, 0xf0, 0xf8, 0x0c, 0x04, 0x04, 0x0c, 0xf8, 0x10, 0x7c, 0xc0,
+    0x80, 0xb8, 0xec, 0xc4, 0x84, 0x84, 0xcc, 0x78, 0xfc, 0x0c, 0x08, 0x08,
+    0x18, 0x10, 0x30, 0x30, 0x20, 0x60, 0x78, 0xcc, 0x84, 0xcc, 0x78, 0xcc,
+    0x84, 0x84, 0xcc, 0x78, 0x78, 0xcc, 0x84, 0x84, 0x8c, 0xfc, 0x34, 0x04,
+    0x0c, 0xf8, 0xc0, 0xc0, 0x00, 0x00, 0x00, 0xc0, 0xc0, 0xc0, 0xc0, 0x00,
+    0x00, 0x00, 0xc0, 0xc0, 0x80, 0x80, 0x01, 0x0e, 0x38, 0xe0, 0x70, 0x0e,
+    0x03, 0xfe, 0x00, 0xfc, 0xfc, 0x70, 0x1c, 0x07, 0x0e, 0x70, 0xc0, 0xf0,
+    0x18, 0x18, 0x10, 0x20, 0x60, 0x60, 0x00, 0x40, 0x60, 0x0c, 0x3e, 0x43,
+    0xcf, 0x9b, 0x91, 0x91, 0x93, 0x9f, 0x40, 0x60, 0x3e, 0x18, 0x18, 0x3c,
+    0x24, 0x24, 0x66, 0x7e, 0x42, 0xc3, 0xc3, 0xf8, 0x8c, 0x84, 0x8c, 0xf8,
+    0xcc, 0x86, 0x86, 0xcc, 0xf8, 0x7c, 0xc0, 0xc0, 0x80, 0x80, 0x80, 0x80,
+    0xc0, 0x40, 0x7c, 0xf8, 0x8c, 0x84, 0x84, 0x84, 0x84, 0x84, 0x8c, 0x9c,
+    0xf0, 0xfc, 0xc0, 0xc0, 0xc0, 0xfc, 0xc0, 0xc0, 0xc0, 0xc0, 0xfc, 0xfe,
+    0xc0, 0xc0, 0xc0, 0xfc, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0x08, 0x3e, 0x60,
+    0x40, 0xc0, 0xc0, 0xce, 0x42, 0x42, 0x62, 0x3e, 0x84, 0x84, 0x84, 0x84,
+    0xfc, 0x84, 0x84, 0x84, 0x84, 0x84, 0xfc, 0x30, 0x30, 0x30, 0x30, 0x30,
+    0x30, 0x30, 0x30, 0xfc, 0x3c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04,
+    0x8c, 0x7c, 0x84, 0x88, 0x90, 0xb0, 0xf0, 0xf0, 0x98, 0x8c, 0x8c, 0x86,
+    0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xfe, 0xe7, 0xe7,
+    0xe7, 0xff, 0xdb, 0xdb, 0xc3, 0xc3, 0xc3, 0xc3, 0xc4, 0xc4, 0xe4, 0xa4,
+    0xb4, 0x94, 0x94, 0x9c, 0x8c, 0x8c, 0x3c, 0x66, 0x42, 0x42, 0xc3, 0xc3,
+    0x42, 0x42, 0x66, 0x3c, 0xfc, 0xc4, 0xc6, 0xc6, 0xcc, 0xf8, 0xc0, 0xc0,
+    0xc0, 0xc0, 0x3c, 0x66, 0x42, 0x42, 0xc3, 0xc3, 0x42, 0x42, 0x66, 0x3c,
+    0x0c, 0xf8, 0x8c, 0x84, 0x8c, 0xf8, 0xf8, 0x8c, 0x8c, 0x86, 0x86, 0x7c,
+    0xc0, 0x80, 0xc0, 0x78, 0x1c, 0x04, 0x04, 0x8c, 0xf8, 0xff, 0x18, 0x18,
+    0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x84, 0x84, 0x84, 0x84, 0x84,
+    0x84, 0x84, 0x84, 0xcc, 0x78, 0xc3, 0x42, 0x42, 0x66, 0x66, 0x24, 0x24,
+    0x3c, 0x18, 0x18, 0x81, 0x81, 0xc3, 0xdb, 0xdb, 0x5a, 0x76, 0x66, 0x66,
+    0x66, 0x43, 0x66, 0x34, 0x1c, 0x18, 0x18, 0x34, 0x66, 0x42, 0xc3, 0xc3,
+    0x66, 0x24, 0x3c, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0xfe, 0x04, 0x0c,
+    0x18, 0x10, 0x30, 0x60, 0x40, 0xc0, 0xfe, 0xe0, 0xc0, 0x80, 0x80, 0x80,
+    0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xc0, 0xe0, 0x80, 0xc0, 0x40, 0x60,
+    0x20, 0x30, 0x10, 0x10, 0x08, 0x08, 0x0c, 0xe0, 0x60, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x60, 0xe0, 0x30, 0x48, 0xcc, 0x84,
+    0xc0, 0x40, 0x78, 0xcc, 0x04, 0x7c, 0xcc, 0x84, 0x8c, 0xfc, 0x80, 0xc0,
+    0xc0, 0xf8, 0xec, 0xc4, 0xc4, 0xc6, 0xc4, 0xcc, 0xf8, 0x3c, 0x64, 0xc0,
+    0xc0, 0xc0, 0xc0, 0x40, 0x3c, 0x04, 0x04, 0x04, 0x74, 0xcc, 0x8c, 0x84,
+    0x84, 0x8c, 0xcc, 0x7c, 0x38, 0xcc, 0x84, 0xfe, 0xfc, 0x80, 0xc0, 0x7c,
+    0x1c, 0x3c, 0x30, 0xfc, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x74,
+    0xcc, 0x8c, 0x84, 0x84, 0x8c, 0xcc, 0x7c, 0x0c, 0x0c, 0x78, 0x80, 0xc0,
+    0xc0, 0xf8, 0xcc, 0xc4, 0xc4, 0xc4, 0xc4, 0xc4, 0xc4, 0x10, 0x30, 0x00,
+    0x70, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0xfc, 0x10, 0x10, 0x00, 0x70,
+    0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x30, 0xe0, 0x40, 0xc0,
+    0xc0, 0xc4, 0xc8, 0xd0, 0xf0, 0xd8, 0xc8, 0xcc, 0xc6, 0xe0, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x30, 0x1c, 0x76, 0xda, 0xdb, 0xdb,
+    0xdb, 0xdb, 0xdb, 0xdb, 0xf8, 0xcc, 0xc4, 0xc4, 0xc4, 0xc4, 0xc4, 0xc4,
+    0x78, 0xcc, 0x84, 0x84, 0x84, 0x84, 0xcc, 0x78, 0xb8, 0xcc, 0xc4, 0xc4,
+    0x84, 0xc4, 0xcc, 0xf8, 0x80, 0x80, 0x80, 0x74, 0xcc, 0x8c, 0x84, 0x84,
+    0x84, 0xcc, 0x7c, 0x04, 0x04, 0x04, 0x9c, 0xe4, 0xc0, 0xc0, 0xc0, 0xc0,
+    0xc0, 0xc0, 0x78, 0xc0, 0xc0, 0xe0, 0x38, 0x0c, 0x0c, 0xf8, 0x20, 0x20,
+    0xfc, 0x60, 0x20, 0x20, 0x20, 0x20, 0x20, 0x3c, 0x84, 0xc4, 0xc4, 0xc4,
+    0xc4, 0xc4, 0xcc, 0x7c, 0x84, 0x84, 0xcc, 0x4c, 0x48, 0x78, 0x30, 0x30,
+    0x81, 0x81, 0xc3, 0xdb, 0x5a, 0x7e, 0x66, 0x66, 0x84, 0xcc, 0x78, 0x30,
+    0x30, 0x78, 0xcc, 0x84, 0x86, 0x84, 0xcc, 0x4c, 0x48, 0x78, 0x30, 0x30,
+    0x30, 0x60, 0xc0, 0xfc, 0x0c, 0x08, 0x10, 0x30, 0x60, 0xc0, 0xfc, 0x0c,
+    0x18, 0x30, 0x30, 0x30, 0x30, 0xe0, 0x20, 0x30, 0x30, 0x30, 0x30, 0x1c,
+    0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0, 0xc0,
+    0xc0, 0xc0, 0x60, 0x30, 0x30, 0x30, 0x30, 0x1c, 0x10, 0x30, 0x30, 0x30,
+    0x30, 0xe0, 0xff, 0x06,
+];
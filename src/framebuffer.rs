@@ -0,0 +1,597 @@
+//! Black and white displays generic over the protocol of their panel controller.
+//!
+//! [`FrameBuffer`] keeps the frame being drawn and the last frame shown, chooses between full
+//! and partial refreshes and uploads only the rows that changed. Supporting a new controller
+//! only takes a [`PanelDriver`] implementation, see [`Ssd1680Panel`] for an example.
+//!
+//! [`Ssd1680Panel`]: crate::ssd1680::Ssd1680Panel
+
+use core::fmt::Debug;
+
+use crate::{
+    BWDisplay, BWDraw, DisplayError, ErrorType, Mirror, Rotation, TransparencySetting, buffer_size,
+    plane::Plane,
+    refresh::{RefreshPolicy, RefreshScheduler},
+};
+
+pub use crate::plane::Area;
+
+/// Kind of panel update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshMode {
+    /// Drives every pixel, clearing the ghosting at the cost of flashing.
+    Full,
+    /// Only drives the pixels differing between the [`RamPlane::Frame`] and the
+    /// [`RamPlane::Reference`].
+    Partial,
+    /// Shorter full refresh leaving more ghosting, drivers without one run a full refresh.
+    Fast,
+}
+
+/// RAM planes of a controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RamPlane {
+    /// Frame to show.
+    Frame,
+    /// Frame shown before, the reference of partial refreshes.
+    Reference,
+}
+
+/// Protocol of a panel controller holding the frame and the reference of partial refreshes in
+/// its RAM.
+///
+/// The RAM uses the [`BWDraw`] buffer layout in the native orientation of the panel.
+pub trait PanelDriver {
+    type Error: Debug;
+
+    /// Resets and configures the controller after power up or deep sleep, with a window
+    /// covering the whole RAM. The RAM content is lost.
+    fn init(&mut self) -> Result<(), Self::Error>;
+
+    /// Restricts the next RAM writes to the bytes containing `area`, starting at its top left.
+    fn set_window(&mut self, area: Area) -> Result<(), Self::Error>;
+
+    /// Writes `data` to `plane` from the current RAM address, successive writes continue where
+    /// the previous one stopped.
+    fn write_plane(&mut self, plane: RamPlane, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Starts updating the panel from the RAM without waiting for the end of the update.
+    fn refresh(&mut self, mode: RefreshMode) -> Result<(), Self::Error>;
+
+    /// Whether a panel update is running.
+    fn is_busy(&mut self) -> Result<bool, Self::Error>;
+
+    /// Waits for the end of the panel update.
+    fn wait_until_idle(&mut self) -> Result<(), Self::Error> {
+        while self.is_busy()? {}
+        Ok(())
+    }
+
+    /// Puts the controller in deep sleep until the next `init`.
+    fn sleep(&mut self) -> Result<(), Self::Error>;
+}
+
+/// RAM writes preceding a refresh, planned by [`Frames::plan_upload`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Upload {
+    /// Whether the front buffer must first be written to [`RamPlane::Reference`], before
+    /// [`Frames::swap`].
+    pub reference: bool,
+    /// Part of the front buffer to write to [`RamPlane::Frame`] after the swap.
+    pub area: Option<Area>,
+}
+
+/// Frame buffers and refresh bookkeeping of the displays, without any I/O: [`FrameBuffer`]
+/// and [`AsyncFrameBuffer`](crate::framebuffer_async::AsyncFrameBuffer) drive their panel with
+/// the [`Upload`]s it plans.
+pub(crate) struct Frames<const BUFFER_SIZE: usize> {
+    pub(crate) frame_buffer: Plane<[u8; BUFFER_SIZE]>,
+    // front buffer: the last frame shown, or being uploaded
+    previous_frame: [u8; BUFFER_SIZE],
+    pub(crate) scheduler: RefreshScheduler,
+    awake: bool,
+    pub(crate) auto_sleep: bool,
+    // whether the RAM of the controller holds the last uploaded frame
+    ram_synced: bool,
+}
+
+impl<const BUFFER_SIZE: usize> Frames<BUFFER_SIZE> {
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the resolution.
+    pub(crate) fn new(width: u16, height: u16) -> Self {
+        assert_eq!(
+            BUFFER_SIZE,
+            buffer_size(width, height),
+            "frame buffer size does not match the display resolution"
+        );
+        Frames {
+            frame_buffer: Plane::new([0; BUFFER_SIZE], width, height),
+            previous_frame: [0; BUFFER_SIZE],
+            scheduler: RefreshScheduler::default(),
+            awake: false,
+            auto_sleep: true,
+            ram_synced: false,
+        }
+    }
+
+    pub(crate) fn is_awake(&self) -> bool {
+        self.awake
+    }
+
+    /// Records the initialization of the controller, which lost its RAM content.
+    pub(crate) fn woke(&mut self) {
+        self.awake = true;
+        self.ram_synced = false;
+    }
+
+    pub(crate) fn slept(&mut self) {
+        self.awake = false;
+    }
+
+    /// Records that the RAM no longer holds the last frame, e.g. after showing other planes.
+    #[cfg(feature = "ssd1680")]
+    pub(crate) fn desync_ram(&mut self) {
+        self.ram_synced = false;
+    }
+
+    /// Chooses between a full and a partial refresh for the changes since the last one.
+    pub(crate) fn refresh_mode(&self, force_full: bool) -> RefreshMode {
+        let dirty = self.frame_buffer.dirty();
+        let full_area = self.frame_buffer.full_area();
+        let full = force_full
+            || self.scheduler.is_full_refresh_due(
+                dirty.map_or(0, |area| area.w as u32 * area.h as u32),
+                full_area.w as u32 * full_area.h as u32,
+            );
+        if full {
+            RefreshMode::Full
+        } else {
+            RefreshMode::Partial
+        }
+    }
+
+    /// Plans the RAM writes of a `mode` refresh of the controller, which must be awake.
+    pub(crate) fn plan_upload(&mut self, mode: RefreshMode) -> Upload {
+        let full = mode != RefreshMode::Partial;
+        let upload = if full || !self.ram_synced {
+            Upload {
+                // partial refreshes compare the frame with the reference in the RAM
+                reference: !full,
+                area: Some(self.frame_buffer.full_area()),
+            }
+        } else {
+            // only the pixels changed since the last refresh need to be sent
+            Upload {
+                reference: false,
+                area: self.frame_buffer.dirty(),
+            }
+        };
+        self.ram_synced = true;
+        upload
+    }
+
+    /// Copies the frame to the front buffer, which becomes the reference of the next partial
+    /// refresh.
+    pub(crate) fn swap(&mut self) {
+        self.previous_frame
+            .copy_from_slice(self.frame_buffer.buffer());
+        self.frame_buffer.clear_dirty();
+    }
+
+    pub(crate) fn front(&self) -> &[u8] {
+        &self.previous_frame
+    }
+
+    /// Bytes of the front buffer containing `rows` of `area`, one slice per row.
+    pub(crate) fn rows(
+        &self,
+        area: Area,
+        rows: core::ops::Range<u16>,
+    ) -> impl Iterator<Item = &[u8]> {
+        let stride = self.frame_buffer.stride();
+        let x_start = (area.x / 8) as usize;
+        let x_end = ((area.x + area.w - 1) / 8) as usize;
+        rows.map(move |y| {
+            let row = y as usize * stride;
+            &self.previous_frame[row + x_start..=row + x_end]
+        })
+    }
+
+    /// Records the end of a `mode` refresh.
+    pub(crate) fn refreshed(&mut self, mode: RefreshMode) {
+        self.scheduler.record_refresh(mode != RefreshMode::Partial);
+    }
+}
+
+/// Refresh started by [`FrameBuffer::start_update`] whose end has not been handled yet.
+#[derive(Debug, Clone, Copy)]
+struct PendingRefresh {
+    mode: RefreshMode,
+    // part of the RAM written for this refresh
+    uploaded: Option<Area>,
+    // next row of `uploaded` to send, the panel update starts once all of them are sent
+    next_row: u16,
+    activated: bool,
+}
+
+/// Rows of the front buffer uploaded by each [`FrameBuffer::poll_refresh`] in double
+/// buffered mode.
+const UPLOAD_ROWS_PER_POLL: u16 = 16;
+
+/// Black and white display of the panel driven by `P`.
+///
+/// `BUFFER_SIZE` must be [`buffer_size`]`(width, height)` of the panel. The display keeps two
+/// buffers of that size: the frame being drawn and the last frame shown, used as the reference
+/// of partial refreshes and as the front buffer in
+/// [double buffered](Self::set_double_buffering) mode.
+pub struct FrameBuffer<P, const BUFFER_SIZE: usize> {
+    pub(crate) panel: P,
+    pub(crate) frames: Frames<BUFFER_SIZE>,
+    pending: Option<PendingRefresh>,
+    double_buffering: bool,
+}
+
+impl<P: PanelDriver, const BUFFER_SIZE: usize> FrameBuffer<P, BUFFER_SIZE> {
+    /// Creates the display of the `width` x `height` panel driven by `panel`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the resolution.
+    pub fn from_panel(panel: P, width: u16, height: u16) -> Self {
+        FrameBuffer {
+            panel,
+            frames: Frames::new(width, height),
+            pending: None,
+            double_buffering: false,
+        }
+    }
+
+    pub fn panel(&self) -> &P {
+        &self.panel
+    }
+
+    /// The panel driver, to send controller specific commands while no refresh is running.
+    pub fn panel_mut(&mut self) -> &mut P {
+        &mut self.panel
+    }
+
+    /// Initializes the controller if it is in deep sleep.
+    ///
+    /// The RAM content is lost in deep sleep, the next refresh after waking up uploads the
+    /// whole frame.
+    pub fn wake(&mut self) -> Result<(), DisplayError<P::Error>> {
+        if !self.frames.is_awake() {
+            self.panel.init()?;
+            self.frames.woke();
+        }
+        Ok(())
+    }
+
+    /// Puts the controller in deep sleep until the next `wake` or refresh.
+    pub fn sleep(&mut self) -> Result<(), DisplayError<P::Error>> {
+        self.wait_refresh()?;
+        if self.frames.is_awake() {
+            self.panel.sleep()?;
+            self.frames.slept();
+        }
+        Ok(())
+    }
+
+    pub fn is_awake(&self) -> bool {
+        self.frames.is_awake()
+    }
+
+    pub fn auto_sleep(&self) -> bool {
+        self.frames.auto_sleep
+    }
+
+    /// When enabled (the default), the controller goes to deep sleep after each refresh.
+    /// Disable it to keep the controller awake between frequent updates.
+    pub fn set_auto_sleep(&mut self, auto_sleep: bool) {
+        self.frames.auto_sleep = auto_sleep;
+    }
+
+    /// Sets the policy choosing between full and partial refreshes, [`RefreshPolicy::default`]
+    /// otherwise.
+    pub fn with_refresh_policy(mut self, policy: RefreshPolicy) -> Self {
+        self.frames.scheduler.set_policy(policy);
+        self
+    }
+
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        self.frames.scheduler.policy()
+    }
+
+    pub fn set_refresh_policy(&mut self, policy: RefreshPolicy) {
+        self.frames.scheduler.set_policy(policy);
+    }
+
+    /// Number of partial refreshes since the last full refresh.
+    pub fn partial_refresh_count(&self) -> u32 {
+        self.frames.scheduler.partial_refresh_count()
+    }
+
+    /// Reports elapsed time for [`RefreshPolicy::Interval`].
+    pub fn advance_time(&mut self, millis: u32) {
+        self.frames.scheduler.advance_time(millis);
+    }
+
+    /// Uploads the whole frame and refreshes it with [`RefreshMode::Fast`]. Fast refreshes
+    /// clear less ghosting than full ones and count as full refreshes for the
+    /// [`RefreshPolicy`].
+    pub fn refresh_fast(&mut self) -> Result<(), DisplayError<P::Error>> {
+        self.update(RefreshMode::Fast)
+    }
+
+    pub fn double_buffering(&self) -> bool {
+        self.double_buffering
+    }
+
+    /// In double buffered mode, [`start_refresh`](Self::start_refresh) swaps the frame being
+    /// drawn (the back buffer) with the front buffer and returns before uploading it: the
+    /// front buffer is sent to the controller a few rows at a time by
+    /// [`poll_refresh`](Self::poll_refresh), so drawing also goes on during the transfer.
+    ///
+    /// The swap copies the new frame to the front buffer, the back buffer keeps the latest
+    /// image for incremental drawing. Disabled by default, where the whole frame is uploaded
+    /// before `start_refresh` returns.
+    pub fn set_double_buffering(&mut self, double_buffering: bool) {
+        self.double_buffering = double_buffering;
+    }
+
+    /// Starts a refresh like [`BWDisplay::refresh`] and returns as soon as the panel update is
+    /// running, without waiting for its end.
+    ///
+    /// The frame is uploaded to the controller before returning (or by `poll_refresh` in
+    /// [double buffered](Self::set_double_buffering) mode), so the frame buffer can be drawn on
+    /// while the panel updates. Call [`poll_refresh`](Self::poll_refresh) until it
+    /// returns `true`, e.g. in the main loop or when the BUSY pin falls, or
+    /// [`wait_refresh`](Self::wait_refresh). A refresh started while another one is running
+    /// first waits for it.
+    pub fn start_refresh(&mut self, force_full: bool) -> Result<(), DisplayError<P::Error>> {
+        self.wait_refresh()?;
+        let mode = self.frames.refresh_mode(force_full);
+        self.start_update(mode)
+    }
+
+    /// Returns whether the refresh started by [`start_refresh`](Self::start_refresh) is over,
+    /// `true` if there is none. The end of the refresh (e.g. going to deep sleep) is handled
+    /// by the call observing it.
+    pub fn poll_refresh(&mut self) -> Result<bool, DisplayError<P::Error>> {
+        let Some(pending) = self.pending else {
+            return Ok(true);
+        };
+        if !pending.activated {
+            self.upload(UPLOAD_ROWS_PER_POLL)?;
+            return Ok(false);
+        }
+        if self.panel.is_busy()? {
+            return Ok(false);
+        }
+        self.end_update()?;
+        Ok(true)
+    }
+
+    /// Waits for the end of the refresh started by [`start_refresh`](Self::start_refresh), if
+    /// any.
+    pub fn wait_refresh(&mut self) -> Result<(), DisplayError<P::Error>> {
+        if self.pending.is_some() {
+            self.upload(u16::MAX)?;
+            self.panel.wait_until_idle()?;
+            self.end_update()?;
+        }
+        Ok(())
+    }
+
+    /// Whether a refresh started by [`start_refresh`](Self::start_refresh) has not been seen
+    /// ending yet.
+    pub fn is_refreshing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.frames.frame_buffer.rotation()
+    }
+
+    /// Rotates the drawing coordinates, `width` and `height` are swapped for 90 and 270 degrees.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.frames.frame_buffer.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.frames.frame_buffer.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.frames.frame_buffer.set_mirror(mirror);
+    }
+
+    /// Shows the frame with a full refresh, with `second_plane` in the reference RAM instead of
+    /// the last frame, like the red plane of tri-color panels.
+    #[cfg(feature = "ssd1680")]
+    pub(crate) fn refresh_planes(
+        &mut self,
+        second_plane: &[u8],
+    ) -> Result<(), DisplayError<P::Error>> {
+        self.wait_refresh()?;
+        self.wake()?;
+        let full_area = self.frames.frame_buffer.full_area();
+        self.panel.set_window(full_area)?;
+        self.panel
+            .write_plane(RamPlane::Frame, self.frames.frame_buffer.buffer())?;
+        self.panel.set_window(full_area)?;
+        self.panel.write_plane(RamPlane::Reference, second_plane)?;
+        self.panel.refresh(RefreshMode::Full)?;
+        self.panel.wait_until_idle()?;
+        // the next partial refresh needs the last frame as its reference again
+        self.frames.desync_ram();
+        self.frames.frame_buffer.clear_dirty();
+        if self.frames.auto_sleep {
+            self.sleep()?;
+        }
+        Ok(())
+    }
+
+    fn update(&mut self, mode: RefreshMode) -> Result<(), DisplayError<P::Error>> {
+        self.wait_refresh()?;
+        self.start_update(mode)?;
+        self.wait_refresh()
+    }
+
+    /// Copies the frame to the front buffer and uploads it, unless in double buffered mode,
+    /// the front buffer becomes the reference of the next partial refresh.
+    fn start_update(&mut self, mode: RefreshMode) -> Result<(), DisplayError<P::Error>> {
+        self.wake()?;
+        let upload = self.frames.plan_upload(mode);
+        if upload.reference {
+            self.panel
+                .write_plane(RamPlane::Reference, self.frames.front())?;
+        }
+        self.frames.swap();
+        self.pending = Some(PendingRefresh {
+            mode,
+            uploaded: upload.area,
+            next_row: upload.area.map_or(0, |area| area.y),
+            activated: false,
+        });
+        if !self.double_buffering {
+            self.upload(u16::MAX)?;
+        }
+        Ok(())
+    }
+
+    /// Sends up to `rows` rows of the front buffer to the frame RAM, then starts the panel
+    /// update once they are all sent.
+    fn upload(&mut self, rows: u16) -> Result<(), DisplayError<P::Error>> {
+        let Some(mut pending) = self.pending.filter(|pending| !pending.activated) else {
+            return Ok(());
+        };
+        if let Some(area) = pending.uploaded {
+            let end = area.y + area.h;
+            if pending.next_row == area.y {
+                self.panel.set_window(area)?;
+            }
+            let last = end.min(pending.next_row.saturating_add(rows));
+            self.write_rows(RamPlane::Frame, area, pending.next_row..last)?;
+            pending.next_row = last;
+            if last < end {
+                self.pending = Some(pending);
+                return Ok(());
+            }
+            // give the next full upload the whole RAM
+            self.panel
+                .set_window(self.frames.frame_buffer.full_area())?;
+        }
+        self.panel.refresh(pending.mode)?;
+        pending.activated = true;
+        self.pending = Some(pending);
+        Ok(())
+    }
+
+    /// Handles the end of the pending panel update.
+    fn end_update(&mut self) -> Result<(), DisplayError<P::Error>> {
+        let Some(pending) = self.pending.take() else {
+            return Ok(());
+        };
+        self.frames.refreshed(pending.mode);
+        if self.frames.auto_sleep {
+            self.sleep()?;
+        } else if let Some(area) = pending.uploaded {
+            // the frame shown becomes the reference of the next partial refresh
+            self.panel.set_window(area)?;
+            self.write_rows(RamPlane::Reference, area, area.y..area.y + area.h)?;
+            self.panel
+                .set_window(self.frames.frame_buffer.full_area())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the bytes of the front buffer containing `rows` of `area` to `plane`.
+    fn write_rows(
+        &mut self,
+        plane: RamPlane,
+        area: Area,
+        rows: core::ops::Range<u16>,
+    ) -> Result<(), P::Error> {
+        for row in self.frames.rows(area, rows) {
+            self.panel.write_plane(plane, row)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: PanelDriver, const BUFFER_SIZE: usize> ErrorType for FrameBuffer<P, BUFFER_SIZE> {
+    type Error = DisplayError<P::Error>;
+}
+
+impl<P: PanelDriver, const BUFFER_SIZE: usize> BWDraw for FrameBuffer<P, BUFFER_SIZE> {
+    fn width(&self) -> u16 {
+        self.frames.frame_buffer.width()
+    }
+
+    fn height(&self) -> u16 {
+        self.frames.frame_buffer.height()
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.set_pixel(x, y, color)
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, Self::Error> {
+        self.frames.frame_buffer.get_pixel(x, y)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.fill(color);
+        Ok(())
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.fill_rect(x, y, w, h, color)
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.set_buffer(buffer)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), Self::Error> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), Self::Error> {
+        self.frames
+            .frame_buffer
+            .draw_buffer(buffer, x, y, w, h, transparency)
+    }
+}
+
+impl<P: PanelDriver, const BUFFER_SIZE: usize> BWDisplay for FrameBuffer<P, BUFFER_SIZE> {
+    fn refresh(&mut self, force_full: bool) -> Result<(), Self::Error> {
+        self.wait_refresh()?;
+        let mode = self.frames.refresh_mode(force_full);
+        self.update(mode)
+    }
+}
@@ -0,0 +1,270 @@
+//! Black and white displays refreshed asynchronously, generic over the protocol of their panel
+//! controller.
+//!
+//! [`AsyncFrameBuffer`] keeps the same frames and chooses the refreshes like
+//! [`FrameBuffer`](crate::framebuffer::FrameBuffer), but awaits its [`AsyncPanelDriver`], so the
+//! executor runs other tasks while the frame is sent and while the panel updates.
+
+use core::fmt::Debug;
+
+use crate::{
+    AsyncBWDisplay, BWDraw, DisplayError, ErrorType, Mirror, Rotation, TransparencySetting,
+    framebuffer::{Area, Frames, RamPlane, RefreshMode},
+    refresh::RefreshPolicy,
+};
+
+/// Asynchronous [`PanelDriver`](crate::framebuffer::PanelDriver).
+#[allow(async_fn_in_trait)]
+pub trait AsyncPanelDriver {
+    type Error: Debug;
+
+    /// Resets and configures the controller after power up or deep sleep, with a window
+    /// covering the whole RAM. The RAM content is lost.
+    async fn init(&mut self) -> Result<(), Self::Error>;
+
+    /// Restricts the next RAM writes to the bytes containing `area`, starting at its top left.
+    async fn set_window(&mut self, area: Area) -> Result<(), Self::Error>;
+
+    /// Writes `data` to `plane` from the current RAM address, successive writes continue where
+    /// the previous one stopped.
+    async fn write_plane(&mut self, plane: RamPlane, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Starts updating the panel from the RAM without waiting for the end of the update.
+    async fn refresh(&mut self, mode: RefreshMode) -> Result<(), Self::Error>;
+
+    /// Waits for the end of the panel update.
+    async fn wait_until_idle(&mut self) -> Result<(), Self::Error>;
+
+    /// Puts the controller in deep sleep until the next `init`.
+    async fn sleep(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Black and white display of the panel driven by `P`, refreshed asynchronously.
+///
+/// `BUFFER_SIZE` must be [`buffer_size`](crate::buffer_size)`(width, height)` of the panel, the
+/// display keeps the frame being drawn and the last frame shown, the reference of partial
+/// refreshes.
+pub struct AsyncFrameBuffer<P, const BUFFER_SIZE: usize> {
+    panel: P,
+    frames: Frames<BUFFER_SIZE>,
+}
+
+impl<P: AsyncPanelDriver, const BUFFER_SIZE: usize> AsyncFrameBuffer<P, BUFFER_SIZE> {
+    /// Creates the display of the `width` x `height` panel driven by `panel`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the resolution.
+    pub fn from_panel(panel: P, width: u16, height: u16) -> Self {
+        AsyncFrameBuffer {
+            panel,
+            frames: Frames::new(width, height),
+        }
+    }
+
+    pub fn panel(&self) -> &P {
+        &self.panel
+    }
+
+    /// The panel driver, to send controller specific commands between refreshes.
+    pub fn panel_mut(&mut self) -> &mut P {
+        &mut self.panel
+    }
+
+    /// Initializes the controller if it is in deep sleep.
+    ///
+    /// The RAM content is lost in deep sleep, the next refresh after waking up uploads the
+    /// whole frame.
+    pub async fn wake(&mut self) -> Result<(), DisplayError<P::Error>> {
+        if !self.frames.is_awake() {
+            self.panel.init().await?;
+            self.frames.woke();
+        }
+        Ok(())
+    }
+
+    /// Puts the controller in deep sleep until the next `wake` or refresh.
+    pub async fn sleep(&mut self) -> Result<(), DisplayError<P::Error>> {
+        if self.frames.is_awake() {
+            self.panel.sleep().await?;
+            self.frames.slept();
+        }
+        Ok(())
+    }
+
+    pub fn is_awake(&self) -> bool {
+        self.frames.is_awake()
+    }
+
+    pub fn auto_sleep(&self) -> bool {
+        self.frames.auto_sleep
+    }
+
+    /// When enabled (the default), the controller goes to deep sleep after each refresh.
+    /// Disable it to keep the controller awake between frequent updates.
+    pub fn set_auto_sleep(&mut self, auto_sleep: bool) {
+        self.frames.auto_sleep = auto_sleep;
+    }
+
+    /// Sets the policy choosing between full and partial refreshes, [`RefreshPolicy::default`]
+    /// otherwise.
+    pub fn with_refresh_policy(mut self, policy: RefreshPolicy) -> Self {
+        self.frames.scheduler.set_policy(policy);
+        self
+    }
+
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        self.frames.scheduler.policy()
+    }
+
+    pub fn set_refresh_policy(&mut self, policy: RefreshPolicy) {
+        self.frames.scheduler.set_policy(policy);
+    }
+
+    /// Number of partial refreshes since the last full refresh.
+    pub fn partial_refresh_count(&self) -> u32 {
+        self.frames.scheduler.partial_refresh_count()
+    }
+
+    /// Reports elapsed time for [`RefreshPolicy::Interval`].
+    pub fn advance_time(&mut self, millis: u32) {
+        self.frames.scheduler.advance_time(millis);
+    }
+
+    /// Uploads the whole frame and refreshes it with [`RefreshMode::Fast`], see
+    /// [`FrameBuffer::refresh_fast`](crate::framebuffer::FrameBuffer::refresh_fast).
+    pub async fn refresh_fast(&mut self) -> Result<(), DisplayError<P::Error>> {
+        self.update(RefreshMode::Fast).await
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.frames.frame_buffer.rotation()
+    }
+
+    /// Rotates the drawing coordinates, `width` and `height` are swapped for 90 and 270 degrees.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.frames.frame_buffer.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.frames.frame_buffer.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.frames.frame_buffer.set_mirror(mirror);
+    }
+
+    /// Uploads the frame, refreshes the panel and waits for the end of the update.
+    async fn update(&mut self, mode: RefreshMode) -> Result<(), DisplayError<P::Error>> {
+        self.wake().await?;
+        let upload = self.frames.plan_upload(mode);
+        if upload.reference {
+            self.panel
+                .write_plane(RamPlane::Reference, self.frames.front())
+                .await?;
+        }
+        self.frames.swap();
+        if let Some(area) = upload.area {
+            self.write_window(RamPlane::Frame, area).await?;
+        }
+        self.panel.refresh(mode).await?;
+        self.panel.wait_until_idle().await?;
+        self.frames.refreshed(mode);
+
+        if self.frames.auto_sleep {
+            self.sleep().await?;
+        } else if let Some(area) = upload.area {
+            // the frame shown becomes the reference of the next partial refresh
+            self.write_window(RamPlane::Reference, area).await?;
+        }
+        Ok(())
+    }
+
+    /// Writes the bytes of the front buffer containing `area` to `plane`.
+    async fn write_window(&mut self, plane: RamPlane, area: Area) -> Result<(), P::Error> {
+        self.panel.set_window(area).await?;
+        for row in self.frames.rows(area, area.y..area.y + area.h) {
+            self.panel.write_plane(plane, row).await?;
+        }
+        // give the next full upload the whole RAM
+        self.panel
+            .set_window(self.frames.frame_buffer.full_area())
+            .await
+    }
+}
+
+impl<P: AsyncPanelDriver, const BUFFER_SIZE: usize> ErrorType for AsyncFrameBuffer<P, BUFFER_SIZE> {
+    type Error = DisplayError<P::Error>;
+}
+
+impl<P: AsyncPanelDriver, const BUFFER_SIZE: usize> BWDraw for AsyncFrameBuffer<P, BUFFER_SIZE> {
+    fn width(&self) -> u16 {
+        self.frames.frame_buffer.width()
+    }
+
+    fn height(&self) -> u16 {
+        self.frames.frame_buffer.height()
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.set_pixel(x, y, color)
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, Self::Error> {
+        self.frames.frame_buffer.get_pixel(x, y)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.fill(color);
+        Ok(())
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.fill_rect(x, y, w, h, color)
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.frames.frame_buffer.set_buffer(buffer)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), Self::Error> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), Self::Error> {
+        self.frames
+            .frame_buffer
+            .draw_buffer(buffer, x, y, w, h, transparency)
+    }
+}
+
+impl<P: AsyncPanelDriver, const BUFFER_SIZE: usize> AsyncBWDisplay
+    for AsyncFrameBuffer<P, BUFFER_SIZE>
+{
+    async fn refresh(&mut self, force_full: bool) -> Result<(), Self::Error> {
+        let mode = self.frames.refresh_mode(force_full);
+        self.update(mode).await
+    }
+}
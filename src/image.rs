@@ -0,0 +1,212 @@
+//! Dithering of grayscale and RGB images into black and white [`BWDraw`] regions.
+//!
+//! Images are converted row by row with a [`Ditherer`], so they can be streamed from a file or
+//! the network without holding them in RAM. The error diffusion methods keep the errors of the
+//! next rows in a scratch buffer provided by the caller, see [`scratch_len`].
+
+use crate::BWDraw;
+use crate::pattern::BAYER;
+use crate::plane::draw_chunks;
+
+/// Layout of the pixels of the source image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// One byte per pixel, 0 is black and 255 is white.
+    Gray8,
+    /// Red, green and blue bytes per pixel.
+    Rgb888,
+}
+
+impl PixelFormat {
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Gray8 => 1,
+            PixelFormat::Rgb888 => 3,
+        }
+    }
+
+    /// Luminance of the first pixel of `pixel`.
+    fn gray(self, pixel: &[u8]) -> u8 {
+        match self {
+            PixelFormat::Gray8 => pixel[0],
+            PixelFormat::Rgb888 => {
+                let luma = 77 * pixel[0] as u32 + 150 * pixel[1] as u32 + 29 * pixel[2] as u32;
+                (luma >> 8) as u8
+            }
+        }
+    }
+}
+
+/// How the gray levels are turned into black and white pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dithering {
+    /// Pixels at least this bright are white, the others are black.
+    Threshold(u8),
+    /// Floyd–Steinberg error diffusion, the most faithful to the gray levels.
+    FloydSteinberg,
+    /// Atkinson error diffusion, with more contrast and less noise in the light and dark areas.
+    Atkinson,
+    /// 8x8 Bayer ordered dithering, aligned to the display like the
+    /// [`Pattern`](crate::pattern::Pattern)s. It needs no scratch buffer and the regions of the
+    /// same gray are regular, which suits charts and maps.
+    Bayer,
+}
+
+impl Dithering {
+    /// Rows of errors kept for the following rows.
+    const fn error_rows(self) -> usize {
+        match self {
+            Dithering::Threshold(_) | Dithering::Bayer => 0,
+            Dithering::FloydSteinberg => 2,
+            Dithering::Atkinson => 3,
+        }
+    }
+}
+
+/// Length of the scratch buffer needed to dither a `width` pixels wide image.
+pub const fn scratch_len(width: u16, dithering: Dithering) -> usize {
+    // one pixel of padding on the left and two on the right
+    (width as usize + 3) * dithering.error_rows()
+}
+
+/// Dithers an image into the display row by row.
+pub struct Ditherer<'a> {
+    x: u16,
+    y: u32,
+    width: u16,
+    format: PixelFormat,
+    dithering: Dithering,
+    errors: &'a mut [i16],
+}
+
+impl<'a> Ditherer<'a> {
+    /// Creates a ditherer drawing a `width` pixels wide image whose top left corner is at
+    /// (`x`, `y`).
+    ///
+    /// # Panics
+    ///
+    /// If `scratch` is shorter than [`scratch_len`].
+    pub fn new(
+        x: u16,
+        y: u16,
+        width: u16,
+        format: PixelFormat,
+        dithering: Dithering,
+        scratch: &'a mut [i16],
+    ) -> Self {
+        let len = scratch_len(width, dithering);
+        assert!(scratch.len() >= len, "scratch buffer too short");
+        let errors = &mut scratch[..len];
+        errors.fill(0);
+        Ditherer {
+            x,
+            y: y as u32,
+            width,
+            format,
+            dithering,
+            errors,
+        }
+    }
+
+    /// Dithers the next row of the image, the parts outside of the display are clipped.
+    ///
+    /// # Panics
+    ///
+    /// If `row` is shorter than the width of the image.
+    pub fn draw_row<D: BWDraw + ?Sized>(
+        &mut self,
+        display: &mut D,
+        row: &[u8],
+    ) -> Result<(), D::Error> {
+        let width = self.width as usize;
+        let bytes_per_pixel = self.format.bytes_per_pixel();
+        assert!(row.len() >= width * bytes_per_pixel, "row too short");
+        let y = self.y;
+        self.y += 1;
+        let stride = width + 3;
+        let visible = y < display.height() as u32;
+        let x = self.x as usize;
+
+        // every pixel is dithered to spread its error, the chunks off the display are dropped
+        draw_chunks(
+            0..width,
+            |i| {
+                let gray = self.format.gray(&row[i * bytes_per_pixel..]);
+                match self.dithering {
+                    Dithering::Threshold(level) => gray >= level,
+                    Dithering::Bayer => {
+                        let black = ((255 - gray as u32) * 64 + 127) / 255;
+                        BAYER[y as usize % 8][(x + i) % 8] as u32 >= black
+                    }
+                    Dithering::FloydSteinberg | Dithering::Atkinson => self.diffuse(i + 1, gray),
+                }
+            },
+            |chunk, i, len| {
+                if !visible || x + i >= display.width() as usize {
+                    return Ok(());
+                }
+                display.draw_buffer(chunk, (x + i) as u16, y as u16, len as u16, 1)
+            },
+        )?;
+
+        // the errors of the next row become the current ones
+        if !self.errors.is_empty() {
+            self.errors.copy_within(stride.., 0);
+            let last_row = self.errors.len() - stride;
+            self.errors[last_row..].fill(0);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the pixel at `index` in the error rows is white and spreads its error.
+    fn diffuse(&mut self, index: usize, gray: u8) -> bool {
+        let errors = &mut *self.errors;
+        let stride = self.width as usize + 3;
+        let value = gray as i16 + errors[index];
+        let white = value >= 128;
+        let error = value - if white { 255 } else { 0 };
+        if self.dithering == Dithering::FloydSteinberg {
+            errors[index + 1] += error * 7 / 16;
+            errors[stride + index - 1] += error * 3 / 16;
+            errors[stride + index] += error * 5 / 16;
+            errors[stride + index + 1] += error / 16;
+        } else {
+            // Atkinson spreads 6/8 of the error
+            let error = error / 8;
+            errors[index + 1] += error;
+            errors[index + 2] += error;
+            errors[stride + index - 1] += error;
+            errors[stride + index] += error;
+            errors[stride + index + 1] += error;
+            errors[2 * stride + index] += error;
+        }
+        white
+    }
+}
+
+/// Dithers the `width` pixels wide image in `data` into the display with its top left corner
+/// at (`x`, `y`). The incomplete last row of `data`, if any, is ignored.
+///
+/// # Panics
+///
+/// If `scratch` is shorter than [`scratch_len`].
+#[allow(clippy::too_many_arguments)]
+pub fn draw_image<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: u16,
+    y: u16,
+    width: u16,
+    format: PixelFormat,
+    data: &[u8],
+    dithering: Dithering,
+    scratch: &mut [i16],
+) -> Result<(), D::Error> {
+    if width == 0 {
+        return Ok(());
+    }
+    let mut ditherer = Ditherer::new(x, y, width, format, dithering, scratch);
+    for row in data.chunks_exact(width as usize * format.bytes_per_pixel()) {
+        ditherer.draw_row(display, row)?;
+    }
+    Ok(())
+}
@@ -1,13 +1,108 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+use pattern::{Fill, Pattern};
+
+/// How the pixels of a source buffer are combined with the frame buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransparencySetting {
+    /// Every pixel of the source overwrites the frame buffer.
     None,
+    /// Black pixels of the source leave the frame buffer untouched.
     BlackTransparent,
+    /// White pixels of the source leave the frame buffer untouched.
     WhiteTransparent,
 }
 
+/// Rotation of the drawing coordinates, clockwise from the native orientation of the panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+/// Mirroring of the drawing coordinates, applied before the [`Rotation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mirror {
+    #[default]
+    None,
+    /// Flips the x axis.
+    Horizontal,
+    /// Flips the y axis.
+    Vertical,
+    /// Flips both axes.
+    Both,
+}
+
+/// Errors reported by the displays of this crate.
+#[derive(Debug)]
+pub enum DisplayError<E> {
+    /// The drawing operation starts outside of the display.
+    OutOfBounds,
+    /// The provided buffer does not have the expected length.
+    InvalidBufferLength,
+    /// Error reported by the display driver, `ssd1680::Error` for the SSD1680 displays.
+    Driver(E),
+}
+
+impl<E> From<E> for DisplayError<E> {
+    fn from(error: E) -> Self {
+        DisplayError::Driver(error)
+    }
+}
+
+pub mod canvas;
+#[cfg(feature = "embedded-graphics")]
+pub mod embedded_graphics;
+pub mod font;
+pub mod framebuffer;
+#[cfg(feature = "async")]
+pub mod framebuffer_async;
+pub mod image;
+pub mod pattern;
+mod plane;
+pub mod primitives;
+pub mod refresh;
+#[cfg(feature = "simulator")]
+pub mod simulator;
 #[cfg(feature = "ssd1680")]
 pub mod ssd1680;
+#[cfg(feature = "async")]
+pub mod ssd1680_async;
+#[cfg(any(feature = "ssd1680", feature = "async"))]
+mod ssd1680_protocol;
+
+/// Number of bytes needed to store a `width` x `height` black and white frame.
+pub const fn buffer_size(width: u16, height: u16) -> usize {
+    width.div_ceil(8) as usize * height as usize
+}
+
+/// Number of bytes needed to store a `width` x `height` frame with 4 gray levels, see [`Gray4`].
+pub const fn gray_buffer_size(width: u16, height: u16) -> usize {
+    width.div_ceil(4) as usize * height as usize
+}
+
+/// Reverses the order of the pixels in each byte of `buffer`, converting a buffer where the
+/// leftmost pixel is the least significant bit to the layout used by [`BWDraw`] (and back).
+pub fn reverse_bit_order(buffer: &mut [u8]) {
+    for byte in buffer {
+        *byte = byte.reverse_bits();
+    }
+}
+
+/// Copies `source` into `destination` while reversing the order of the pixels in each byte,
+/// see [`reverse_bit_order`]. Stops at the end of the shortest buffer.
+pub fn copy_reversed_bit_order(source: &[u8], destination: &mut [u8]) {
+    for (destination, source) in destination.iter_mut().zip(source) {
+        *destination = source.reverse_bits();
+    }
+}
+
 pub trait ErrorType {
     /// Error type
     type Error: core::fmt::Debug;
@@ -15,9 +110,48 @@ pub trait ErrorType {
 
 /// White : true, Black : false
 /// In the buffer, one byte corresponds to 8 pixels on the x axis.
-pub trait BWDisplay: ErrorType {
+/// Pixels are stored row by row and the most significant bit of a byte is the leftmost pixel,
+/// like in the SSD1680 RAM: pixel (`x`, `y`) is bit `7 - x % 8` of byte
+/// `y * width.div_ceil(8) + x / 8`.
+///
+/// Drawing operations starting outside of the display fail, the parts of a rectangle or
+/// buffer going past the right or bottom edge are clipped.
+/// Rows of the buffers given to `draw_buffer` are padded to a whole number of bytes.
+pub trait BWDraw: ErrorType {
+    fn width(&self) -> u16;
+    fn height(&self) -> u16;
     fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Self::Error>;
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, Self::Error>;
     fn fill(&mut self, color: bool) -> Result<(), Self::Error>;
+    /// Fills the `w` x `h` rectangle whose top left corner is at (`x`, `y`).
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), Self::Error> {
+        if x >= self.width() || y >= self.height() {
+            // the implementation reports the start outside of the display
+            return self.set_pixel(x, y, color);
+        }
+        let w = w.min(self.width() - x);
+        let h = h.min(self.height() - y);
+        for j in y..y + h {
+            for i in x..x + w {
+                self.set_pixel(i, j, color)?;
+            }
+        }
+        Ok(())
+    }
+    /// Fills the whole display with `pattern`.
+    fn fill_pattern(&mut self, pattern: Pattern) -> Result<(), Self::Error> {
+        let (width, height) = (self.width(), self.height());
+        pattern::fill_rect(self, 0, 0, width, height, Fill::Pattern(pattern))
+    }
+    /// Replaces the whole frame, `buffer` must be exactly [`buffer_size`] bytes long.
+    /// It is copied as is, in the native orientation of the panel.
     fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Self::Error>;
     fn draw_buffer(
         &mut self,
@@ -36,5 +170,78 @@ pub trait BWDisplay: ErrorType {
         h: u16,
         transparency: TransparencySetting,
     ) -> Result<(), Self::Error>;
+}
+
+/// Black and white display: a [`BWDraw`] frame buffer shown on the panel by `refresh`.
+pub trait BWDisplay: BWDraw {
     fn refresh(&mut self, force_full: bool) -> Result<(), Self::Error>;
 }
+
+/// Black and white display refreshed without blocking, while the panel updates the other tasks
+/// of the executor keep running. See [`BWDisplay`].
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncBWDisplay: BWDraw {
+    async fn refresh(&mut self, force_full: bool) -> Result<(), Self::Error>;
+}
+
+/// Colors of a black, white and red (or yellow) display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriColor {
+    White,
+    Black,
+    Red,
+}
+
+/// Display with a black and white plane and a red (or yellow) accent plane.
+///
+/// Both planes use the [`BWDraw`] buffer layout, a set bit in the red plane shows a red
+/// pixel whatever the value of the black and white plane.
+pub trait TriColorDisplay: ErrorType {
+    fn width(&self) -> u16;
+    fn height(&self) -> u16;
+    fn set_pixel(&mut self, x: u16, y: u16, color: TriColor) -> Result<(), Self::Error>;
+    fn get_pixel(&self, x: u16, y: u16) -> Result<TriColor, Self::Error>;
+    fn fill(&mut self, color: TriColor) -> Result<(), Self::Error>;
+    /// Draws `bw_buffer` on the black and white plane and `red_buffer` on the red plane.
+    fn draw_buffer(
+        &mut self,
+        bw_buffer: &[u8],
+        red_buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), Self::Error>;
+    /// Uploads both planes and runs a full refresh.
+    fn refresh(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Levels of a 4 gray display, from black to white.
+///
+/// Gray buffers use 2 bits per pixel, 4 pixels per byte: pixel (`x`, `y`) is bits
+/// `7 - 2 * (x % 4)` and `6 - 2 * (x % 4)` of byte `y * width.div_ceil(4) + x / 4` and its
+/// value is the index of its level, from `0b00` for black to `0b11` for white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gray4 {
+    Black,
+    DarkGray,
+    LightGray,
+    White,
+}
+
+impl Gray4 {
+    /// Level whose value is the 2 least significant bits of `bits`.
+    pub const fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => Gray4::Black,
+            1 => Gray4::DarkGray,
+            2 => Gray4::LightGray,
+            _ => Gray4::White,
+        }
+    }
+
+    pub const fn bits(self) -> u8 {
+        self as u8
+    }
+}
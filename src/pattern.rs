@@ -0,0 +1,140 @@
+//! 8x8 fill patterns emulating shades of gray on black and white panels.
+
+use crate::{BWDraw, plane::draw_chunks};
+
+/// 8x8 tile repeated over the display, aligned to the display coordinates so that the
+/// patterns of adjacent shapes join seamlessly.
+///
+/// Each row is a byte laid out like the [`BWDraw`] buffers: the most significant bit is the
+/// leftmost pixel and a set bit is white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    rows: [u8; 8],
+}
+
+/// 8x8 Bayer matrix, the order in which the pixels of a tile turn black as the density grows.
+pub(crate) const BAYER: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+impl Pattern {
+    pub const WHITE: Pattern = Pattern::new([0xff; 8]);
+    pub const BLACK: Pattern = Pattern::new([0x00; 8]);
+    pub const CHECKERBOARD: Pattern =
+        Pattern::new([0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa]);
+    /// Black lines going up to the right, every 4 pixels.
+    pub const FORWARD_DIAGONAL: Pattern =
+        Pattern::new([0xee, 0xdd, 0xbb, 0x77, 0xee, 0xdd, 0xbb, 0x77]);
+    /// Black lines going down to the right, every 4 pixels.
+    pub const BACKWARD_DIAGONAL: Pattern =
+        Pattern::new([0x77, 0xbb, 0xdd, 0xee, 0x77, 0xbb, 0xdd, 0xee]);
+    /// Both diagonal hatches.
+    pub const DIAGONAL_CROSS: Pattern =
+        Pattern::new([0x66, 0x99, 0x99, 0x66, 0x66, 0x99, 0x99, 0x66]);
+    /// Black horizontal lines, every 4 pixels.
+    pub const HORIZONTAL_LINES: Pattern =
+        Pattern::new([0x00, 0xff, 0xff, 0xff, 0x00, 0xff, 0xff, 0xff]);
+    /// Black vertical lines, every 4 pixels.
+    pub const VERTICAL_LINES: Pattern = Pattern::new([0x77; 8]);
+
+    /// Creates a pattern from the rows of a custom tile.
+    pub const fn new(rows: [u8; 8]) -> Self {
+        Pattern { rows }
+    }
+
+    /// Returns an ordered dither pattern where `percent` % of the pixels are black, from white
+    /// at 0 to black at 100 (or more).
+    pub const fn gray(percent: u8) -> Self {
+        let percent = if percent > 100 { 100 } else { percent } as u16;
+        // number of black pixels in the tile
+        let black = ((percent * 64 + 50) / 100) as u8;
+        let mut rows = [0xff; 8];
+        let mut y = 0;
+        while y < 8 {
+            let mut x = 0;
+            while x < 8 {
+                if BAYER[y][x] < black {
+                    rows[y] &= !(0x80 >> x);
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        Pattern { rows }
+    }
+
+    /// Swaps the black and white pixels.
+    pub const fn inverted(self) -> Self {
+        let mut rows = self.rows;
+        let mut y = 0;
+        while y < 8 {
+            rows[y] = !rows[y];
+            y += 1;
+        }
+        Pattern { rows }
+    }
+
+    pub const fn rows(&self) -> [u8; 8] {
+        self.rows
+    }
+
+    /// Color of the pattern at (`x`, `y`) on the display, white : true.
+    pub const fn color_at(&self, x: u16, y: u16) -> bool {
+        self.rows[y as usize % 8] & (0x80 >> (x % 8)) != 0
+    }
+}
+
+/// How a shape is filled: with a single color or with a [`Pattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fill {
+    Solid(bool),
+    Pattern(Pattern),
+}
+
+impl From<bool> for Fill {
+    fn from(color: bool) -> Self {
+        Fill::Solid(color)
+    }
+}
+
+impl From<Pattern> for Fill {
+    fn from(pattern: Pattern) -> Self {
+        Fill::Pattern(pattern)
+    }
+}
+
+/// Fills the `w` x `h` rectangle whose top left corner is at (`x`, `y`) with `fill`.
+pub(crate) fn fill_rect<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: u16,
+    y: u16,
+    w: u16,
+    h: u16,
+    fill: Fill,
+) -> Result<(), D::Error> {
+    let pattern = match fill {
+        Fill::Solid(color) => return display.fill_rect(x, y, w, h, color),
+        Fill::Pattern(pattern) => pattern,
+    };
+    if x >= display.width() || y >= display.height() {
+        // the implementation reports the start outside of the display, like for solid fills
+        return display.fill_rect(x, y, w, h, pattern.color_at(x, y));
+    }
+    let w = w.min(display.width() - x);
+    let h = h.min(display.height() - y);
+    for row in y..y + h {
+        draw_chunks(
+            x as usize..(x + w) as usize,
+            |column| pattern.color_at(column as u16, row),
+            |chunk, column, len| display.draw_buffer(chunk, column as u16, row, len as u16, 1),
+        )?;
+    }
+    Ok(())
+}
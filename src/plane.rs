@@ -0,0 +1,393 @@
+//! Drawing operations on a single 1 bit per pixel plane, shared by the display implementations.
+
+use core::ops::Range;
+
+use super::{DisplayError, Mirror, Rotation, TransparencySetting, buffer_size};
+
+/// A `width` x `height` plane stored with the [`crate::BWDisplay`] buffer layout.
+///
+/// `width` and `height` are the dimensions of the buffer, drawing operations use coordinates
+/// transformed by the rotation and mirroring of the plane.
+pub(crate) struct Plane<B> {
+    buffer: B,
+    width: u16,
+    height: u16,
+    rotation: Rotation,
+    mirror: Mirror,
+    dirty: Option<Area>,
+}
+
+/// Rectangle in buffer coordinates, i.e. in the native orientation of the panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Area {
+    pub x: u16,
+    pub y: u16,
+    pub w: u16,
+    pub h: u16,
+}
+
+impl Area {
+    /// Smallest area containing both `self` and `other`.
+    fn union(self, other: Area) -> Area {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Area {
+            x,
+            y,
+            w: (self.x + self.w).max(other.x + other.w) - x,
+            h: (self.y + self.h).max(other.y + other.h) - y,
+        }
+    }
+}
+
+/// Refresh bookkeeping, only needed by the displays.
+impl<B: AsRef<[u8]>> Plane<B> {
+    /// Bounding box of the pixels modified since the last call to `clear_dirty`.
+    pub(crate) fn dirty(&self) -> Option<Area> {
+        self.dirty
+    }
+
+    pub(crate) fn clear_dirty(&mut self) {
+        self.dirty = None;
+    }
+
+    /// Number of bytes of a row of the buffer.
+    pub(crate) fn stride(&self) -> usize {
+        self.width.div_ceil(8) as usize
+    }
+}
+
+impl<B: AsRef<[u8]> + AsMut<[u8]>> Plane<B> {
+    /// `buffer` must be [`buffer_size`] bytes long.
+    pub(crate) fn new(buffer: B, width: u16, height: u16) -> Self {
+        debug_assert_eq!(buffer.as_ref().len(), buffer_size(width, height));
+        Plane {
+            buffer,
+            width,
+            height,
+            rotation: Rotation::default(),
+            mirror: Mirror::default(),
+            dirty: None,
+        }
+    }
+
+    /// Width in drawing coordinates.
+    pub(crate) fn width(&self) -> u16 {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => self.width,
+            Rotation::Rotate90 | Rotation::Rotate270 => self.height,
+        }
+    }
+
+    /// Height in drawing coordinates.
+    pub(crate) fn height(&self) -> u16 {
+        match self.rotation {
+            Rotation::Rotate0 | Rotation::Rotate180 => self.height,
+            Rotation::Rotate90 | Rotation::Rotate270 => self.width,
+        }
+    }
+
+    pub(crate) fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    pub(crate) fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    pub(crate) fn mirror(&self) -> Mirror {
+        self.mirror
+    }
+
+    pub(crate) fn set_mirror(&mut self, mirror: Mirror) {
+        self.mirror = mirror;
+    }
+
+    pub(crate) fn buffer(&self) -> &[u8] {
+        self.buffer.as_ref()
+    }
+
+    pub(crate) fn into_buffer(self) -> B {
+        self.buffer
+    }
+
+    fn mark_dirty(&mut self, area: Area) {
+        self.dirty = Some(match self.dirty {
+            Some(dirty) => dirty.union(area),
+            None => area,
+        });
+    }
+
+    fn mark_all_dirty(&mut self) {
+        self.dirty = Some(self.full_area());
+    }
+
+    /// Area covering the whole buffer.
+    pub(crate) fn full_area(&self) -> Area {
+        Area {
+            x: 0,
+            y: 0,
+            w: self.width,
+            h: self.height,
+        }
+    }
+
+    pub(crate) fn set_pixel<E>(
+        &mut self,
+        x: u16,
+        y: u16,
+        color: bool,
+    ) -> Result<(), DisplayError<E>> {
+        if x >= self.width() || y >= self.height() {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let (x, y) = self.to_physical(x, y);
+        self.set_physical_pixel(x, y, color);
+        Ok(())
+    }
+
+    pub(crate) fn get_pixel<E>(&self, x: u16, y: u16) -> Result<bool, DisplayError<E>> {
+        if x >= self.width() || y >= self.height() {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let (x, y) = self.to_physical(x, y);
+        let address = get_address(x, y, self.width);
+        Ok(self.buffer.as_ref()[address.buffer_position] & (0x80 >> address.byte_offset) != 0)
+    }
+
+    pub(crate) fn fill(&mut self, color: bool) {
+        self.buffer.as_mut().fill((color as u8) * 255);
+        self.mark_all_dirty();
+    }
+
+    pub(crate) fn fill_rect<E>(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), DisplayError<E>> {
+        if x >= self.width() || y >= self.height() {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let w = w.min(self.width() - x);
+        let h = h.min(self.height() - y);
+        if w == 0 || h == 0 {
+            return Ok(());
+        }
+        // rotations and mirroring keep rectangles axis aligned
+        let (x0, y0) = self.to_physical(x, y);
+        let (x1, y1) = self.to_physical(x + w - 1, y + h - 1);
+        self.fill_physical_rect(
+            x0.min(x1),
+            y0.min(y1),
+            x0.abs_diff(x1) + 1,
+            y0.abs_diff(y1) + 1,
+            color,
+        );
+        Ok(())
+    }
+
+    /// Replaces the whole buffer, `buffer` is in the native orientation of the panel.
+    pub(crate) fn set_buffer<E>(&mut self, buffer: &[u8]) -> Result<(), DisplayError<E>> {
+        if buffer.len() != self.buffer.as_ref().len() {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        self.buffer.as_mut().copy_from_slice(buffer);
+        self.mark_all_dirty();
+        Ok(())
+    }
+
+    pub(crate) fn draw_buffer<E>(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), DisplayError<E>> {
+        if x >= self.width() || y >= self.height() {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let stride = w.div_ceil(8) as usize;
+        if buffer.len() < stride * h as usize {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        let visible_w = w.min(self.width() - x);
+        let visible_h = h.min(self.height() - y);
+
+        if self.rotation == Rotation::Rotate0 && self.mirror == Mirror::None {
+            self.blit(buffer, stride, x, y, visible_w, visible_h, transparency);
+            return Ok(());
+        }
+
+        // transformed buffers are drawn pixel by pixel
+        for j in 0..visible_h {
+            let source_row = &buffer[j as usize * stride..];
+            for i in 0..visible_w {
+                let color = source_row[i as usize / 8] & (0x80 >> (i % 8)) != 0;
+                let transparent = match transparency {
+                    TransparencySetting::None => false,
+                    TransparencySetting::BlackTransparent => !color,
+                    TransparencySetting::WhiteTransparent => color,
+                };
+                if !transparent {
+                    let (x, y) = self.to_physical(x + i, y + j);
+                    self.set_physical_pixel(x, y, color);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts drawing coordinates, which must be on the plane, to buffer coordinates.
+    fn to_physical(&self, x: u16, y: u16) -> (u16, u16) {
+        let (x, y) = match self.mirror {
+            Mirror::None => (x, y),
+            Mirror::Horizontal => (self.width() - 1 - x, y),
+            Mirror::Vertical => (x, self.height() - 1 - y),
+            Mirror::Both => (self.width() - 1 - x, self.height() - 1 - y),
+        };
+        match self.rotation {
+            Rotation::Rotate0 => (x, y),
+            Rotation::Rotate90 => (self.width - 1 - y, x),
+            Rotation::Rotate180 => (self.width - 1 - x, self.height - 1 - y),
+            Rotation::Rotate270 => (y, self.height - 1 - x),
+        }
+    }
+
+    fn set_physical_pixel(&mut self, x: u16, y: u16, color: bool) {
+        self.mark_dirty(Area { x, y, w: 1, h: 1 });
+        let address = get_address(x, y, self.width);
+        let buffer = self.buffer.as_mut();
+        buffer[address.buffer_position] = (buffer[address.buffer_position]
+            & !(0x80 >> address.byte_offset))
+            | (((color as u8) << 7) >> address.byte_offset);
+    }
+
+    /// Fills a rectangle of the buffer, which must be entirely on the plane.
+    fn fill_physical_rect(&mut self, x: u16, y: u16, w: u16, h: u16, color: bool) {
+        self.mark_dirty(Area { x, y, w, h });
+        let first = get_address(x, 0, self.width);
+        let last = get_address(x + w - 1, 0, self.width);
+        let value = (color as u8) * 255;
+        let buffer = self.buffer.as_mut();
+        for j in y..y + h {
+            let row = get_address(0, j, self.width).buffer_position;
+            let start = row + first.buffer_position;
+            let end = row + last.buffer_position;
+            if start == end {
+                let mask = span_mask(first.byte_offset, last.byte_offset);
+                buffer[start] = (buffer[start] & !mask) | (value & mask);
+                continue;
+            }
+            let mask = span_mask(first.byte_offset, 7);
+            buffer[start] = (buffer[start] & !mask) | (value & mask);
+            buffer[start + 1..end].fill(value);
+            let mask = span_mask(0, last.byte_offset);
+            buffer[end] = (buffer[end] & !mask) | (value & mask);
+        }
+    }
+
+    /// Copies the first `w` x `h` pixels of `buffer` byte by byte, the destination rectangle
+    /// must be entirely on the plane.
+    #[allow(clippy::too_many_arguments)]
+    fn blit(
+        &mut self,
+        buffer: &[u8],
+        stride: usize,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) {
+        self.mark_dirty(Area { x, y, w, h });
+        let plane = self.buffer.as_mut();
+        for j in 0..h {
+            let source_row = &buffer[j as usize * stride..];
+            for i in 0..w.div_ceil(8) {
+                // only the pixels of the source byte that are on the display are drawn
+                let count = (w - i * 8).min(8);
+                let mask = 0xff_u8 << (8 - count);
+                let source = source_row[i as usize];
+
+                let address = get_address(x + i * 8, y + j, self.width);
+                let position = address.buffer_position;
+                let offset = address.byte_offset;
+                plane[position] = blend_byte(
+                    plane[position],
+                    source >> offset,
+                    mask >> offset,
+                    transparency,
+                );
+
+                // the last bits of the source byte land at the start of the next byte
+                let carry_mask = mask.checked_shl((8 - offset).into()).unwrap_or(0);
+                if carry_mask != 0 {
+                    plane[position + 1] = blend_byte(
+                        plane[position + 1],
+                        source << (8 - offset),
+                        carry_mask,
+                        transparency,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Packs the pixels `columns` of a row, whose colors are given in order by `color`, into
+/// chunks of up to 64 pixels laid out like the [`crate::BWDraw`] buffers, and passes each chunk
+/// to `draw` with the column of its first pixel and its number of pixels.
+pub(crate) fn draw_chunks<E>(
+    columns: Range<usize>,
+    mut color: impl FnMut(usize) -> bool,
+    mut draw: impl FnMut(&[u8], usize, usize) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut start = columns.start;
+    while start < columns.end {
+        let len = (columns.end - start).min(64);
+        let mut chunk = [0u8; 8];
+        for i in 0..len {
+            if color(start + i) {
+                chunk[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        draw(&chunk[..len.div_ceil(8)], start, len)?;
+        start += len;
+    }
+    Ok(())
+}
+
+/// Combines the bits of `source` selected by `mask` into `target`.
+fn blend_byte(target: u8, source: u8, mask: u8, transparency: TransparencySetting) -> u8 {
+    match transparency {
+        TransparencySetting::None => (target & !mask) | (source & mask),
+        // only the white (1) bits of the source are drawn
+        TransparencySetting::BlackTransparent => target | (source & mask),
+        // only the black (0) bits of the source are drawn
+        TransparencySetting::WhiteTransparent => target & (source | !mask),
+    }
+}
+
+/// Mask of the bits used by the pixels `first..=last` of a byte.
+fn span_mask(first: u8, last: u8) -> u8 {
+    (0xff_u8 >> first) & (0xff_u8 << (7 - last))
+}
+
+struct Address {
+    pub buffer_position: usize,
+    pub byte_offset: u8,
+}
+
+fn get_address(x: u16, y: u16, width: u16) -> Address {
+    let frambuffer_position = x as usize / 8 + y as usize * width.div_ceil(8) as usize;
+    let byte_offset = (x % 8) as u8;
+    Address {
+        buffer_position: frambuffer_position,
+        byte_offset,
+    }
+}
@@ -0,0 +1,506 @@
+//! Geometric primitives for [`BWDraw`]s.
+//!
+//! Coordinates are signed so that shapes can extend past any edge of the display, the parts
+//! outside of the display are clipped. Filled shapes are drawn with horizontal spans given to
+//! [`BWDraw::fill_rect`], which the displays of this crate fill a byte at a time.
+//!
+//! The lines and filled shapes accept a color or a [`Pattern`](crate::pattern::Pattern).
+
+use crate::BWDraw;
+use crate::pattern::{self, Fill};
+
+/// Sub-pixel units per pixel of the polygon rasterizer.
+const SUBPIXELS: i64 = 16;
+
+/// Draws the line from (`x0`, `y0`) to (`x1`, `y1`), both ends included.
+pub fn draw_line<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let fill = fill.into();
+    if y0 == y1 || x0 == x1 {
+        return fill_area(
+            display,
+            x0.min(x1),
+            y0.min(y1),
+            x0.max(x1),
+            y0.max(y1),
+            fill,
+        );
+    }
+    // Bresenham's algorithm
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let step_x = if x0 < x1 { 1 } else { -1 };
+    let step_y = if y0 < y1 { 1 } else { -1 };
+    let mut error = dx + dy;
+    let (mut x, mut y) = (x0, y0);
+    loop {
+        set_pixel(display, x, y, fill)?;
+        if x == x1 && y == y1 {
+            return Ok(());
+        }
+        let double_error = 2 * error;
+        if double_error >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if double_error <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+}
+
+/// Draws a `thickness` pixels wide line centered on the line from (`x0`, `y0`) to (`x1`, `y1`),
+/// both ends included.
+pub fn draw_thick_line<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x0: i32,
+    y0: i32,
+    x1: i32,
+    y1: i32,
+    thickness: u16,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let fill = fill.into();
+    if thickness <= 1 {
+        return draw_line(display, x0, y0, x1, y1, fill);
+    }
+    let center = |x: i32, y: i32| {
+        (
+            x as i64 * SUBPIXELS + SUBPIXELS / 2,
+            y as i64 * SUBPIXELS + SUBPIXELS / 2,
+        )
+    };
+    let (start, end) = (center(x0, y0), center(x1, y1));
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = ((dx * dx + dy * dy) as u64).isqrt() as i64;
+    if length == 0 {
+        let top_left = (thickness as i32 - 1) / 2;
+        return fill_rect(
+            display,
+            x0 - top_left,
+            y0 - top_left,
+            thickness,
+            thickness,
+            fill,
+        );
+    }
+    let half = thickness as i64 * SUBPIXELS / 2;
+    // half a pixel past each end so that the end pixels are covered
+    let (along_x, along_y) = (dx * SUBPIXELS / 2 / length, dy * SUBPIXELS / 2 / length);
+    let (across_x, across_y) = (-dy * half / length, dx * half / length);
+    let (start, end) = (
+        (start.0 - along_x, start.1 - along_y),
+        (end.0 + along_x, end.1 + along_y),
+    );
+    let corners = [
+        (start.0 + across_x, start.1 + across_y),
+        (end.0 + across_x, end.1 + across_y),
+        (end.0 - across_x, end.1 - across_y),
+        (start.0 - across_x, start.1 - across_y),
+    ];
+    fill_subpixel_polygon(display, &corners, |corner| *corner, fill)
+}
+
+/// Fills the `w` x `h` rectangle whose top left corner is at (`x`, `y`).
+pub fn fill_rect<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    w: u16,
+    h: u16,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    if w == 0 || h == 0 {
+        return Ok(());
+    }
+    fill_area(display, x, y, x + w as i32 - 1, y + h as i32 - 1, fill)
+}
+
+/// Draws the outline of the `w` x `h` rectangle whose top left corner is at (`x`, `y`).
+pub fn draw_rect<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    w: u16,
+    h: u16,
+    color: bool,
+) -> Result<(), D::Error> {
+    if w == 0 || h == 0 {
+        return Ok(());
+    }
+    let (right, bottom) = (x + w as i32 - 1, y + h as i32 - 1);
+    fill_area(display, x, y, right, y, color)?;
+    fill_area(display, x, bottom, right, bottom, color)?;
+    fill_area(display, x, y + 1, x, bottom - 1, color)?;
+    fill_area(display, right, y + 1, right, bottom - 1, color)
+}
+
+/// Draws the outline of a rectangle whose corners are rounded with a `radius` pixels radius.
+pub fn draw_rounded_rect<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    w: u16,
+    h: u16,
+    radius: u16,
+    color: bool,
+) -> Result<(), D::Error> {
+    let span = rounded_rect_span(x, w, h, radius);
+    convex_outline(y, h as i32, span, |y, left, right| {
+        fill_area(display, left, y, right, y, color)
+    })
+}
+
+/// Fills a rectangle whose corners are rounded with a `radius` pixels radius.
+pub fn fill_rounded_rect<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    w: u16,
+    h: u16,
+    radius: u16,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let fill = fill.into();
+    let span = rounded_rect_span(x, w, h, radius);
+    for row in 0..h as i32 {
+        let (left, right) = span(row);
+        fill_area(display, left, y + row, right, y + row, fill)?;
+    }
+    Ok(())
+}
+
+/// Draws the outline of the circle centered on (`cx`, `cy`).
+pub fn draw_circle<D: BWDraw + ?Sized>(
+    display: &mut D,
+    cx: i32,
+    cy: i32,
+    radius: u16,
+    color: bool,
+) -> Result<(), D::Error> {
+    draw_ellipse(display, cx, cy, radius, radius, color)
+}
+
+/// Fills the circle centered on (`cx`, `cy`).
+pub fn fill_circle<D: BWDraw + ?Sized>(
+    display: &mut D,
+    cx: i32,
+    cy: i32,
+    radius: u16,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    fill_ellipse(display, cx, cy, radius, radius, fill)
+}
+
+/// Draws the outline of the ellipse centered on (`cx`, `cy`) with the horizontal radius `rx`
+/// and the vertical radius `ry`.
+pub fn draw_ellipse<D: BWDraw + ?Sized>(
+    display: &mut D,
+    cx: i32,
+    cy: i32,
+    rx: u16,
+    ry: u16,
+    color: bool,
+) -> Result<(), D::Error> {
+    let span = ellipse_span(cx, rx, ry);
+    convex_outline(cy - ry as i32, 2 * ry as i32 + 1, span, |y, left, right| {
+        fill_area(display, left, y, right, y, color)
+    })
+}
+
+/// Fills the ellipse centered on (`cx`, `cy`) with the horizontal radius `rx` and the vertical
+/// radius `ry`.
+pub fn fill_ellipse<D: BWDraw + ?Sized>(
+    display: &mut D,
+    cx: i32,
+    cy: i32,
+    rx: u16,
+    ry: u16,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let fill = fill.into();
+    let span = ellipse_span(cx, rx, ry);
+    let top = cy - ry as i32;
+    for row in 0..2 * ry as i32 + 1 {
+        let (left, right) = span(row);
+        fill_area(display, left, top + row, right, top + row, fill)?;
+    }
+    Ok(())
+}
+
+/// Draws the part of the circle centered on (`cx`, `cy`) going from `start_angle` over
+/// `sweep_angle` degrees. Angles are measured clockwise from the positive x axis, a negative
+/// `sweep_angle` goes counterclockwise.
+pub fn draw_arc<D: BWDraw + ?Sized>(
+    display: &mut D,
+    cx: i32,
+    cy: i32,
+    radius: u16,
+    start_angle: i32,
+    sweep_angle: i32,
+    color: bool,
+) -> Result<(), D::Error> {
+    if sweep_angle.unsigned_abs() >= 360 {
+        return draw_circle(display, cx, cy, radius, color);
+    }
+    let (start_angle, sweep_angle) = if sweep_angle < 0 {
+        (start_angle + sweep_angle, -sweep_angle)
+    } else {
+        (start_angle, sweep_angle)
+    };
+    let start = direction(start_angle);
+    let end = direction(start_angle + sweep_angle);
+    // positive when `b` is clockwise from `a`, the y axis going down
+    let cross = |a: (i64, i64), b: (i64, i64)| a.0 * b.1 - a.1 * b.0;
+    let in_arc = |point: (i64, i64)| {
+        if sweep_angle <= 180 {
+            cross(start, point) >= 0 && cross(point, end) >= 0
+        } else {
+            !(cross(end, point) > 0 && cross(point, start) > 0)
+        }
+    };
+    let span = ellipse_span(cx, radius, radius);
+    convex_outline(
+        cy - radius as i32,
+        2 * radius as i32 + 1,
+        span,
+        |y, left, right| {
+            for x in left..=right {
+                if in_arc(((x - cx) as i64, (y - cy) as i64)) {
+                    set_pixel(display, x, y, color)?;
+                }
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Draws the outline of the polygon going through `points`, the last point being joined to
+/// the first one.
+pub fn draw_polygon<D: BWDraw + ?Sized>(
+    display: &mut D,
+    points: &[(i32, i32)],
+    color: bool,
+) -> Result<(), D::Error> {
+    for (i, &(x0, y0)) in points.iter().enumerate() {
+        let (x1, y1) = points[(i + 1) % points.len()];
+        draw_line(display, x0, y0, x1, y1, color)?;
+    }
+    Ok(())
+}
+
+/// Fills the polygon going through `points` with the even-odd rule, without allocating.
+///
+/// The points are the centers of pixels and the pixels whose center is on a right or bottom
+/// edge are left out, so that polygons sharing an edge do not overlap. Draw the outline with
+/// [`draw_polygon`] to include them.
+pub fn fill_polygon<D: BWDraw + ?Sized>(
+    display: &mut D,
+    points: &[(i32, i32)],
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let fill = fill.into();
+    fill_subpixel_polygon(
+        display,
+        points,
+        |&(x, y)| {
+            (
+                x as i64 * SUBPIXELS + SUBPIXELS / 2,
+                y as i64 * SUBPIXELS + SUBPIXELS / 2,
+            )
+        },
+        fill,
+    )
+}
+
+/// Fills the pixels whose center is inside the polygon going through `points`, given in
+/// [`SUBPIXELS`] units by `position`. Each row is sampled at the center of its pixels and the
+/// crossings with the edges are visited from left to right by selecting the smallest crossing
+/// after the previous one, instead of sorting them in a buffer.
+fn fill_subpixel_polygon<D: BWDraw + ?Sized, P>(
+    display: &mut D,
+    points: &[P],
+    position: impl Fn(&P) -> (i64, i64),
+    fill: Fill,
+) -> Result<(), D::Error> {
+    if points.len() < 3 {
+        return Ok(());
+    }
+    let (top, bottom) = points
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(top, bottom), point| {
+            let y = position(point).1;
+            (top.min(y), bottom.max(y))
+        });
+    let top = top.div_euclid(SUBPIXELS).max(0);
+    let bottom = bottom
+        .div_euclid(SUBPIXELS)
+        .min(display.height() as i64 - 1);
+    // first pixel whose center is at or after `x`
+    let first_pixel = |x: i64| (x - SUBPIXELS / 2 + SUBPIXELS - 1).div_euclid(SUBPIXELS) as i32;
+    for y in top..=bottom {
+        let sample_y = y * SUBPIXELS + SUBPIXELS / 2;
+        let crossings = points.iter().enumerate().filter_map(|(i, point)| {
+            let (x0, y0) = position(point);
+            let (x1, y1) = position(&points[(i + 1) % points.len()]);
+            ((y0 <= sample_y) != (y1 <= sample_y))
+                .then(|| (x0 + (sample_y - y0) * (x1 - x0) / (y1 - y0), i))
+        });
+        let mut previous = None;
+        let mut span_start = None;
+        while let Some(crossing) = crossings
+            .clone()
+            .filter(|&crossing| previous.is_none_or(|previous| crossing > previous))
+            .min()
+        {
+            previous = Some(crossing);
+            match span_start.take() {
+                None => span_start = Some(crossing.0),
+                Some(start) => {
+                    let (left, right) = (first_pixel(start), first_pixel(crossing.0) - 1);
+                    fill_area(display, left, y as i32, right, y as i32, fill)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Calls `draw_span(y, left, right)` with the pixels of the outline of a convex shape made of
+/// the `height` rows starting at `top`, `span(row)` returning the first and last pixel of each
+/// row. A pixel is inside of the shape when the rows above and below cover it.
+fn convex_outline<E>(
+    top: i32,
+    height: i32,
+    span: impl Fn(i32) -> (i32, i32),
+    mut draw_span: impl FnMut(i32, i32, i32) -> Result<(), E>,
+) -> Result<(), E> {
+    for row in 0..height {
+        let (left, right) = span(row);
+        let y = top + row;
+        let (mut inner_left, mut inner_right) = (left + 1, right - 1);
+        if row == 0 || row == height - 1 {
+            inner_right = inner_left - 1;
+        } else {
+            for (neighbor_left, neighbor_right) in [span(row - 1), span(row + 1)] {
+                inner_left = inner_left.max(neighbor_left);
+                inner_right = inner_right.min(neighbor_right);
+            }
+        }
+        if inner_left > inner_right {
+            draw_span(y, left, right)?;
+        } else {
+            draw_span(y, left, inner_left - 1)?;
+            draw_span(y, inner_right + 1, right)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the first and last pixel of each row of an ellipse centered on `cx`.
+fn ellipse_span(cx: i32, rx: u16, ry: u16) -> impl Fn(i32) -> (i32, i32) {
+    move |row| {
+        let extent = ellipse_extent(rx, ry, row.abs_diff(ry as i32));
+        (cx - extent, cx + extent)
+    }
+}
+
+/// Returns the first and last pixel of each row of a rounded rectangle starting at `x`.
+fn rounded_rect_span(x: i32, w: u16, h: u16, radius: u16) -> impl Fn(i32) -> (i32, i32) {
+    let radius = radius.min(w / 2).min(h / 2) as i32;
+    let right = x + w as i32 - 1;
+    move |row| {
+        let dy = (radius - row).max(row - (h as i32 - 1 - radius));
+        let inset = if dy > 0 {
+            radius - ellipse_extent(radius as u16, radius as u16, dy as u32)
+        } else {
+            0
+        };
+        (x + inset, right - inset)
+    }
+}
+
+/// Half width of the row `dy` rows away from the center of an ellipse with the radii `rx` and
+/// `ry`, the pixels whose center is inside the ellipse whose radii are half a pixel longer.
+fn ellipse_extent(rx: u16, ry: u16, dy: u32) -> i32 {
+    let width = 2 * rx as u128 + 1;
+    let height = 2 * ry as u128 + 1;
+    let dy = 2 * dy as u128;
+    if dy >= height {
+        return -1;
+    }
+    (width * width * (height * height - dy * dy) / (4 * height * height)).isqrt() as i32
+}
+
+/// Sines of the angles from 0 to 90 degrees, scaled by 2^14.
+const SINES: [i64; 91] = [
+    0, 286, 572, 857, 1143, 1428, 1713, 1997, 2280, 2563, 2845, 3126, 3406, 3686, 3964, 4240, 4516,
+    4790, 5063, 5334, 5604, 5872, 6138, 6402, 6664, 6924, 7182, 7438, 7692, 7943, 8192, 8438, 8682,
+    8923, 9162, 9397, 9630, 9860, 10087, 10311, 10531, 10749, 10963, 11174, 11381, 11585, 11786,
+    11982, 12176, 12365, 12551, 12733, 12911, 13085, 13255, 13421, 13583, 13741, 13894, 14044,
+    14189, 14330, 14466, 14598, 14726, 14849, 14968, 15082, 15191, 15296, 15396, 15491, 15582,
+    15668, 15749, 15826, 15897, 15964, 16026, 16083, 16135, 16182, 16225, 16262, 16294, 16322,
+    16344, 16362, 16374, 16382, 16384,
+];
+
+/// Cosine and sine of `degrees`, scaled by 2^14.
+fn direction(degrees: i32) -> (i64, i64) {
+    let degrees = degrees.rem_euclid(360) as usize;
+    let sine = |degrees: usize| match degrees {
+        0..=90 => SINES[degrees],
+        91..=180 => SINES[180 - degrees],
+        181..=270 => -SINES[degrees - 180],
+        _ => -SINES[360 - degrees],
+    };
+    (sine((degrees + 90) % 360), sine(degrees))
+}
+
+/// Fills the rectangle from (`left`, `top`) to (`right`, `bottom`) included, clipped to the
+/// display.
+fn fill_area<D: BWDraw + ?Sized>(
+    display: &mut D,
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    let (left, top) = (left.max(0), top.max(0));
+    let right = right.min(display.width() as i32 - 1);
+    let bottom = bottom.min(display.height() as i32 - 1);
+    if left > right || top > bottom {
+        return Ok(());
+    }
+    pattern::fill_rect(
+        display,
+        left as u16,
+        top as u16,
+        (right - left + 1) as u16,
+        (bottom - top + 1) as u16,
+        fill.into(),
+    )
+}
+
+fn set_pixel<D: BWDraw + ?Sized>(
+    display: &mut D,
+    x: i32,
+    y: i32,
+    fill: impl Into<Fill>,
+) -> Result<(), D::Error> {
+    if x < 0 || y < 0 || x >= display.width() as i32 || y >= display.height() as i32 {
+        return Ok(());
+    }
+    let (x, y) = (x as u16, y as u16);
+    let color = match fill.into() {
+        Fill::Solid(color) => color,
+        Fill::Pattern(pattern) => pattern.color_at(x, y),
+    };
+    display.set_pixel(x, y, color)
+}
@@ -0,0 +1,109 @@
+//! Choice between full and partial refreshes.
+
+/// When to run a full refresh instead of a partial one.
+///
+/// Partial refreshes are fast and don't flash the panel, but ghosting builds up until the next
+/// full refresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshPolicy {
+    /// Full refresh after the given number of partial refreshes.
+    FixedCount {
+        count: u32,
+    },
+    /// Full refresh once the given number of milliseconds elapsed since the last one, the time
+    /// is reported with [`RefreshScheduler::advance_time`].
+    Interval {
+        millis: u32,
+    },
+    /// Full refresh when the area changed since the last refresh covers at least the given
+    /// percentage of the display.
+    ChangedRatio {
+        percent: u8,
+    },
+    AlwaysFull,
+    AlwaysPartial,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        RefreshPolicy::FixedCount { count: 5 }
+    }
+}
+
+/// Keeps track of the refreshes to apply a [`RefreshPolicy`].
+///
+/// The first refresh is always a full refresh.
+#[derive(Debug, Clone)]
+pub struct RefreshScheduler {
+    policy: RefreshPolicy,
+    partial_count: u32,
+    elapsed_millis: u32,
+    first_refresh: bool,
+}
+
+impl RefreshScheduler {
+    pub fn new(policy: RefreshPolicy) -> Self {
+        RefreshScheduler {
+            policy,
+            partial_count: 0,
+            elapsed_millis: 0,
+            first_refresh: true,
+        }
+    }
+
+    pub fn policy(&self) -> RefreshPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: RefreshPolicy) {
+        self.policy = policy;
+    }
+
+    /// Number of partial refreshes since the last full refresh.
+    pub fn partial_refresh_count(&self) -> u32 {
+        self.partial_count
+    }
+
+    /// Milliseconds reported with `advance_time` since the last full refresh.
+    pub fn elapsed_millis(&self) -> u32 {
+        self.elapsed_millis
+    }
+
+    pub fn advance_time(&mut self, millis: u32) {
+        self.elapsed_millis = self.elapsed_millis.saturating_add(millis);
+    }
+
+    /// Whether the next refresh should be a full refresh, given the number of pixels in the area
+    /// changed since the last refresh and the total number of pixels of the display.
+    pub fn is_full_refresh_due(&self, changed_pixels: u32, total_pixels: u32) -> bool {
+        if self.first_refresh {
+            return true;
+        }
+        match self.policy {
+            RefreshPolicy::FixedCount { count } => self.partial_count >= count,
+            RefreshPolicy::Interval { millis } => self.elapsed_millis >= millis,
+            RefreshPolicy::ChangedRatio { percent } => {
+                changed_pixels as u64 * 100 >= total_pixels as u64 * percent as u64
+            }
+            RefreshPolicy::AlwaysFull => true,
+            RefreshPolicy::AlwaysPartial => false,
+        }
+    }
+
+    /// Records a refresh of the display.
+    pub fn record_refresh(&mut self, full: bool) {
+        if full {
+            self.partial_count = 0;
+            self.elapsed_millis = 0;
+            self.first_refresh = false;
+        } else {
+            self.partial_count = self.partial_count.saturating_add(1);
+        }
+    }
+}
+
+impl Default for RefreshScheduler {
+    fn default() -> Self {
+        RefreshScheduler::new(RefreshPolicy::default())
+    }
+}
@@ -0,0 +1,303 @@
+//! In-memory [`BWDisplay`] to test drawing code on the host.
+//!
+//! Each refresh copies the frame buffer to the displayed frame, which can be saved as a PBM or
+//! PNG image to compare screens with golden images.
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    BWDisplay, BWDraw, DisplayError, ErrorType, Mirror, Rotation, TransparencySetting, buffer_size,
+    plane::Plane,
+    refresh::{RefreshPolicy, RefreshScheduler},
+};
+
+/// Image format of the frames saved by [`SimulatorDisplay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    Pbm,
+    Png,
+}
+
+impl SnapshotFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            SnapshotFormat::Pbm => "pbm",
+            SnapshotFormat::Png => "png",
+        }
+    }
+}
+
+/// Display simulated in memory.
+///
+/// Refreshes follow the [`RefreshPolicy`] of the display like on a real panel and are counted.
+pub struct SimulatorDisplay {
+    frame_buffer: Plane<Vec<u8>>,
+    displayed_frame: Vec<u8>,
+    scheduler: RefreshScheduler,
+    full_refreshes: usize,
+    partial_refreshes: usize,
+    snapshots: Option<(PathBuf, SnapshotFormat)>,
+}
+
+impl SimulatorDisplay {
+    /// Creates a `width` x `height` display, all black like an uninitialized frame buffer.
+    pub fn new(width: u16, height: u16) -> Self {
+        SimulatorDisplay {
+            frame_buffer: Plane::new(vec![0; buffer_size(width, height)], width, height),
+            displayed_frame: vec![0; buffer_size(width, height)],
+            scheduler: RefreshScheduler::default(),
+            full_refreshes: 0,
+            partial_refreshes: 0,
+            snapshots: None,
+        }
+    }
+
+    /// Sets the policy choosing between full and partial refreshes, [`RefreshPolicy::default`]
+    /// otherwise.
+    pub fn with_refresh_policy(mut self, policy: RefreshPolicy) -> Self {
+        self.scheduler.set_policy(policy);
+        self
+    }
+
+    pub fn set_refresh_policy(&mut self, policy: RefreshPolicy) {
+        self.scheduler.set_policy(policy);
+    }
+
+    /// Reports elapsed time for [`RefreshPolicy::Interval`].
+    pub fn advance_time(&mut self, millis: u32) {
+        self.scheduler.advance_time(millis);
+    }
+
+    pub fn rotation(&self) -> Rotation {
+        self.frame_buffer.rotation()
+    }
+
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.frame_buffer.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.frame_buffer.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.frame_buffer.set_mirror(mirror);
+    }
+
+    /// Number of full refreshes since the creation of the display.
+    pub fn full_refreshes(&self) -> usize {
+        self.full_refreshes
+    }
+
+    /// Number of partial refreshes since the creation of the display.
+    pub fn partial_refreshes(&self) -> usize {
+        self.partial_refreshes
+    }
+
+    /// Frame shown by the last refresh, in the native orientation of the panel.
+    pub fn displayed_frame(&self) -> &[u8] {
+        &self.displayed_frame
+    }
+
+    /// Saves every refreshed frame in `directory` as `frame_0001.<extension>`,
+    /// `frame_0002.<extension>`, ...
+    pub fn save_snapshots(&mut self, directory: impl Into<PathBuf>, format: SnapshotFormat) {
+        self.snapshots = Some((directory.into(), format));
+    }
+
+    /// Writes the displayed frame as a binary PBM image.
+    pub fn write_pbm<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let area = self.frame_buffer.full_area();
+        write!(writer, "P4\n{} {}\n", area.w, area.h)?;
+        // PBM uses 1 for black
+        let inverted: Vec<u8> = self.displayed_frame.iter().map(|byte| !byte).collect();
+        writer.write_all(&inverted)
+    }
+
+    /// Writes the displayed frame as a 1 bit grayscale PNG image.
+    pub fn write_png<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let area = self.frame_buffer.full_area();
+        let stride = self.frame_buffer.stride();
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&u32::from(area.w).to_be_bytes());
+        header.extend_from_slice(&u32::from(area.h).to_be_bytes());
+        // bit depth 1, grayscale, default compression, filter and interlace methods
+        header.extend_from_slice(&[1, 0, 0, 0, 0]);
+
+        // every scanline starts with its filter type, 0 for none
+        let mut scanlines = Vec::with_capacity((stride + 1) * area.h as usize);
+        for row in self.displayed_frame.chunks(stride) {
+            scanlines.push(0);
+            scanlines.extend_from_slice(row);
+        }
+
+        writer.write_all(b"\x89PNG\r\n\x1a\n")?;
+        write_png_chunk(&mut writer, b"IHDR", &header)?;
+        write_png_chunk(&mut writer, b"IDAT", &zlib_stored(&scanlines))?;
+        write_png_chunk(&mut writer, b"IEND", &[])
+    }
+
+    fn save_snapshot(&self, path: &Path, format: SnapshotFormat) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        match format {
+            SnapshotFormat::Pbm => self.write_pbm(&mut writer)?,
+            SnapshotFormat::Png => self.write_png(&mut writer)?,
+        }
+        writer.flush()
+    }
+}
+
+impl ErrorType for SimulatorDisplay {
+    type Error = DisplayError<io::Error>;
+}
+
+impl BWDraw for SimulatorDisplay {
+    fn width(&self) -> u16 {
+        self.frame_buffer.width()
+    }
+
+    fn height(&self) -> u16 {
+        self.frame_buffer.height()
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), DisplayError<io::Error>> {
+        self.frame_buffer.set_pixel(x, y, color)
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, DisplayError<io::Error>> {
+        self.frame_buffer.get_pixel(x, y)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), DisplayError<io::Error>> {
+        self.frame_buffer.fill(color);
+        Ok(())
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), DisplayError<io::Error>> {
+        self.frame_buffer.fill_rect(x, y, w, h, color)
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), DisplayError<io::Error>> {
+        self.frame_buffer.set_buffer(buffer)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), DisplayError<io::Error>> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), DisplayError<io::Error>> {
+        self.frame_buffer
+            .draw_buffer(buffer, x, y, w, h, transparency)
+    }
+}
+
+impl BWDisplay for SimulatorDisplay {
+    fn refresh(&mut self, force_full: bool) -> Result<(), DisplayError<io::Error>> {
+        let dirty = self.frame_buffer.dirty();
+        let full_area = self.frame_buffer.full_area();
+        let full = force_full
+            || self.scheduler.is_full_refresh_due(
+                dirty.map_or(0, |area| area.w as u32 * area.h as u32),
+                full_area.w as u32 * full_area.h as u32,
+            );
+
+        if full {
+            self.full_refreshes += 1;
+        } else {
+            self.partial_refreshes += 1;
+        }
+        self.scheduler.record_refresh(full);
+        self.frame_buffer.clear_dirty();
+        self.displayed_frame
+            .copy_from_slice(self.frame_buffer.buffer());
+
+        if let Some((directory, format)) = &self.snapshots {
+            let index = self.full_refreshes + self.partial_refreshes;
+            let path = directory.join(std::format!("frame_{index:04}.{}", format.extension()));
+            self.save_snapshot(&path, *format)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_png_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// zlib stream made of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 0xffff;
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // a single empty final block
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        stream.push(last as u8);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(data: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
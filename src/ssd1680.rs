@@ -1,3 +1,8 @@
+//! SSD1680 displays driven with the blocking `embedded-hal` traits.
+//!
+//! The controller is driven directly over SPI with the commands of its datasheet, the same
+//! sequences as [`AsyncSsd1680Display`](crate::ssd1680_async::AsyncSsd1680Display).
+
 use core::fmt::Debug;
 
 use embedded_hal::{
@@ -5,52 +10,525 @@ use embedded_hal::{
     digital::{InputPin, OutputPin},
     spi::SpiDevice,
 };
-use ssd1680_rs::{self, SSD1680, error::Error};
 
-use super::{BWDisplay, ErrorType, TransparencySetting};
+pub use super::ssd1680_protocol::Error;
+use super::{
+    BWDisplay, BWDraw, DisplayError, ErrorType, Gray4, Mirror, Rotation, TransparencySetting,
+    TriColor, TriColorDisplay,
+    framebuffer::{Area, FrameBuffer, PanelDriver, RamPlane, RefreshMode},
+    gray_buffer_size,
+    plane::{Plane, draw_chunks},
+    refresh::RefreshPolicy,
+    ssd1680_protocol::{
+        Command, FULL_UPDATE, PARTIAL_UPDATE, RESET_DELAY_MS, activation_commands, command,
+        deep_sleep_command, init_commands, window_commands,
+    },
+};
+
+/// Error of the controller of an [`Ssd1680Display`].
+type DriverError<RST, DC, BUSY, SPI> = Error<
+    <SPI as embedded_hal::spi::ErrorType>::Error,
+    <RST as embedded_hal::digital::ErrorType>::Error,
+    <DC as embedded_hal::digital::ErrorType>::Error,
+    <BUSY as embedded_hal::digital::ErrorType>::Error,
+>;
+
+/// Display update sequences running the waveform written to the LUT register instead of
+/// loading one from the OTP, in display mode 1 (full) and 2 (partial).
+const DISPLAY_WITH_LOADED_LUT: u8 = 0xC7;
+const DISPLAY_PARTIAL_WITH_LOADED_LUT: u8 = 0xCF;
+/// Display update sequence measuring the temperature.
+const LOAD_TEMPERATURE: u8 = 0xB1;
+
+/// Refresh waveform of the SSD1680 and the driving voltages it is designed for.
+///
+/// Panel vendors publish waveforms as 159 bytes: the 153 bytes of the LUT register followed by
+/// the end option, gate voltage, three source voltages and VCOM, see [`Waveform::from_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Waveform {
+    /// Voltage levels, phase timings and frame rates of the LUT register.
+    pub lut: [u8; 153],
+    pub end_option: u8,
+    pub gate_voltage: u8,
+    /// VSH1, VSH2 and VSL.
+    pub source_voltages: [u8; 3],
+    pub vcom: u8,
+}
+
+impl Waveform {
+    /// Splits a waveform in the 159 bytes layout published by panel vendors.
+    pub const fn from_bytes(bytes: [u8; 159]) -> Self {
+        let mut lut = [0; 153];
+        let mut i = 0;
+        while i < lut.len() {
+            lut[i] = bytes[i];
+            i += 1;
+        }
+        Waveform {
+            lut,
+            end_option: bytes[153],
+            gate_voltage: bytes[154],
+            source_voltages: [bytes[155], bytes[156], bytes[157]],
+            vcom: bytes[158],
+        }
+    }
+}
+
+/// Custom waveforms of the refreshes of a display, `None` keeps the waveform of the OTP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Waveforms {
+    pub full: Option<&'static Waveform>,
+    pub partial: Option<&'static Waveform>,
+    /// Waveform of [`Ssd1680Display::refresh_fast`], which falls back to the full refresh
+    /// without it.
+    pub fast: Option<&'static Waveform>,
+}
+
+/// Waveforms used while the temperature of the panel is at most `max_temperature` °C.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemperatureBand {
+    pub max_temperature: i8,
+    pub waveforms: Waveforms,
+}
+
+/// Waveforms of the first band covering `temperature`, or of the last band above all of them.
+fn waveforms_at(bands: &[TemperatureBand], temperature: i8) -> Waveforms {
+    bands
+        .iter()
+        .find(|band| temperature <= band.max_temperature)
+        .or(bands.last())
+        .map_or(Waveforms::default(), |band| band.waveforms)
+}
+
+/// 4 gray levels waveform of the Waveshare 2.9" V2 panel, also suitable for most GDEY029T94
+/// based panels.
+#[rustfmt::skip]
+pub const GRAY4_WAVEFORM: Waveform = Waveform::from_bytes([
+    // voltage levels of the 5 LUTs, one per combination of the BW and red RAM bits and VCOM
+    0x00, 0x60, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x60, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x28, 0x60, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2A, 0x60, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // phase timings and repeats of the 12 groups
+    0x00, 0x02, 0x00, 0x05, 0x14, 0x00, 0x00,
+    0x1E, 0x1E, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x00, 0x02, 0x00, 0x05, 0x14, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // frame rates and gate scan selection
+    0x24, 0x22, 0x22, 0x22, 0x23, 0x32, 0x00, 0x00, 0x00,
+    // end option, gate voltage, source voltages and VCOM
+    0x22, 0x17, 0x41, 0xAE, 0x32, 0x28,
+]);
+
+/// [`PanelDriver`] of the SSD1680, writing the frame to the BW RAM and the reference of partial
+/// refreshes to the red RAM.
+pub struct Ssd1680Panel<RST, DC, BUSY, DELAY, SPI> {
+    rst: RST,
+    dc: DC,
+    busy: BUSY,
+    delay: DELAY,
+    spi: SPI,
+    // whole RAM, in buffer coordinates
+    full_area: Area,
+    // sorted by temperature, empty to use the waveforms of the OTP
+    waveforms: &'static [TemperatureBand],
+    // temperature choosing the band, read from the sensor when not set
+    temperature: Option<i8>,
+    // waveform of every refresh while set, instead of the ones of the temperature bands
+    pub(crate) forced_waveform: Option<&'static Waveform>,
+    // RAM plane written by the last command, whose writes can go on without a new command
+    writing: Option<RamPlane>,
+}
+
+impl<RST: OutputPin, DC: OutputPin, BUSY: InputPin, DELAY: DelayNs, SPI: SpiDevice>
+    Ssd1680Panel<RST, DC, BUSY, DELAY, SPI>
+{
+    /// Creates the driver of a `width` x `height` panel, in its native orientation.
+    pub fn new(
+        rst: RST,
+        dc: DC,
+        busy: BUSY,
+        delay: DELAY,
+        spi: SPI,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        Ssd1680Panel {
+            rst,
+            dc,
+            busy,
+            delay,
+            spi,
+            full_area: Area {
+                x: 0,
+                y: 0,
+                w: width,
+                h: height,
+            },
+            waveforms: &[],
+            temperature: None,
+            forced_waveform: None,
+            writing: None,
+        }
+    }
+
+    /// See [`Ssd1680Display::with_waveforms`].
+    pub fn with_waveforms(mut self, bands: &'static [TemperatureBand]) -> Self {
+        self.waveforms = bands;
+        self
+    }
+
+    pub fn waveforms(&self) -> &'static [TemperatureBand] {
+        self.waveforms
+    }
+
+    pub fn set_waveforms(&mut self, bands: &'static [TemperatureBand]) {
+        self.waveforms = bands;
+    }
+
+    pub fn temperature(&self) -> Option<i8> {
+        self.temperature
+    }
+
+    /// See [`Ssd1680Display::set_temperature`].
+    pub fn set_temperature(&mut self, temperature: Option<i8>) {
+        self.temperature = temperature;
+    }
+
+    fn send_command(&mut self, command: u8) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.writing = None;
+        self.dc.set_low().map_err(Error::DataCommand)?;
+        self.spi.write(&[command]).map_err(Error::Spi)
+    }
 
-const FRAME_BUFFER_SIZE: usize = 176 * 296;
+    fn send_data(&mut self, data: &[u8]) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.dc.set_high().map_err(Error::DataCommand)?;
+        self.spi.write(data).map_err(Error::Spi)
+    }
+
+    fn send(&mut self, commands: &[Command]) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        for command in commands {
+            self.send_command(command.code)?;
+            if !command.data().is_empty() {
+                self.send_data(command.data())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the display update `sequence` and waits for its end.
+    fn activate(&mut self, sequence: u8) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.send(&activation_commands(sequence))?;
+        self.wait_until_idle()
+    }
+
+    /// Writes `waveform` to the LUT register and the voltage registers.
+    fn write_waveform(
+        &mut self,
+        waveform: &Waveform,
+    ) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.send_command(command::WRITE_LUT_REGISTER)?;
+        self.send_data(&waveform.lut)?;
+        self.send_command(command::END_OPTION)?;
+        self.send_data(&[waveform.end_option])?;
+        self.send_command(command::GATE_DRIVING_VOLTAGE)?;
+        self.send_data(&[waveform.gate_voltage])?;
+        self.send_command(command::SOURCE_DRIVING_VOLTAGE)?;
+        self.send_data(&waveform.source_voltages)?;
+        self.send_command(command::WRITE_VCOM_REGISTER)?;
+        self.send_data(&[waveform.vcom])
+    }
+
+    /// Measures the temperature with the internal sensor, the controller must be awake.
+    ///
+    /// The value is read back over SDA, which needs a bidirectional SPI bus or the MISO line
+    /// wired to it.
+    fn sense_temperature(&mut self) -> Result<i8, DriverError<RST, DC, BUSY, SPI>> {
+        self.activate(LOAD_TEMPERATURE)?;
+        // 12 bits two's complement in 1/16 °C, the first byte holds the whole degrees
+        let mut temperature = [0; 2];
+        self.send_command(command::READ_TEMPERATURE_REGISTER)?;
+        self.dc.set_high().map_err(Error::DataCommand)?;
+        self.spi.read(&mut temperature).map_err(Error::Spi)?;
+        Ok(temperature[0] as i8)
+    }
+}
+
+impl<RST: OutputPin, DC: OutputPin, BUSY: InputPin, DELAY: DelayNs, SPI: SpiDevice> PanelDriver
+    for Ssd1680Panel<RST, DC, BUSY, DELAY, SPI>
+{
+    type Error = DriverError<RST, DC, BUSY, SPI>;
+
+    /// Hardware and software reset followed by the configuration of the panel geometry.
+    fn init(&mut self) -> Result<(), Self::Error> {
+        self.rst.set_low().map_err(Error::Reset)?;
+        self.delay.delay_ms(RESET_DELAY_MS);
+        self.rst.set_high().map_err(Error::Reset)?;
+        self.delay.delay_ms(RESET_DELAY_MS);
+        self.wait_until_idle()?;
+        self.send_command(command::SW_RESET)?;
+        self.wait_until_idle()?;
+        self.send(&init_commands(self.full_area))?;
+        self.wait_until_idle()
+    }
+
+    fn set_window(&mut self, area: Area) -> Result<(), Self::Error> {
+        self.send(&window_commands(area))
+    }
+
+    fn write_plane(&mut self, plane: RamPlane, data: &[u8]) -> Result<(), Self::Error> {
+        if self.writing != Some(plane) {
+            self.send_command(match plane {
+                RamPlane::Frame => command::WRITE_BW_RAM,
+                RamPlane::Reference => command::WRITE_RED_RAM,
+            })?;
+            self.writing = Some(plane);
+        }
+        // the controller keeps its RAM address between data transfers
+        self.send_data(data)
+    }
+
+    /// Runs the custom waveform of the current temperature band, if any, or the one of the OTP.
+    fn refresh(&mut self, mode: RefreshMode) -> Result<(), Self::Error> {
+        let waveform = match self.forced_waveform {
+            Some(waveform) => Some(waveform),
+            None => {
+                let waveforms = if self.waveforms.is_empty() {
+                    Waveforms::default()
+                } else {
+                    let temperature = match self.temperature {
+                        Some(temperature) => temperature,
+                        None => self.sense_temperature()?,
+                    };
+                    waveforms_at(self.waveforms, temperature)
+                };
+                match mode {
+                    RefreshMode::Full => waveforms.full,
+                    RefreshMode::Partial => waveforms.partial,
+                    RefreshMode::Fast => waveforms.fast.or(waveforms.full),
+                }
+            }
+        };
+        let full = mode != RefreshMode::Partial;
+        let sequence = match waveform {
+            Some(waveform) => {
+                self.write_waveform(waveform)?;
+                if full {
+                    DISPLAY_WITH_LOADED_LUT
+                } else {
+                    DISPLAY_PARTIAL_WITH_LOADED_LUT
+                }
+            }
+            None if full => FULL_UPDATE,
+            None => PARTIAL_UPDATE,
+        };
+        self.send(&activation_commands(sequence))
+    }
+
+    fn is_busy(&mut self) -> Result<bool, Self::Error> {
+        self.busy.is_high().map_err(Error::Busy)
+    }
+
+    fn wait_until_idle(&mut self) -> Result<(), Self::Error> {
+        while self.is_busy()? {
+            self.delay.delay_ms(1);
+        }
+        Ok(())
+    }
+
+    fn sleep(&mut self) -> Result<(), Self::Error> {
+        self.send(&[deep_sleep_command()])
+    }
+}
 
-pub struct Ssd1680Display<
+/// Black and white SSD1680 display.
+///
+/// `BUFFER_SIZE` must be [`buffer_size`](crate::buffer_size)`(width, height)` of the panel,
+/// e.g. `buffer_size(128, 296)` for a 2.9" panel, see [`FrameBuffer`].
+pub type Ssd1680Display<RST, DC, BUSY, DELAY, SPI, const BUFFER_SIZE: usize> =
+    FrameBuffer<Ssd1680Panel<RST, DC, BUSY, DELAY, SPI>, BUFFER_SIZE>;
+
+impl<
     RST: OutputPin,
     DC: OutputPin,
     BUSY: InputPin,
     DELAY: DelayNs,
     SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> FrameBuffer<Ssd1680Panel<RST, DC, BUSY, DELAY, SPI>, BUFFER_SIZE>
+{
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the `width` x `height` resolution.
+    pub fn new(
+        rst: RST,
+        dc: DC,
+        busy: BUSY,
+        delay: DELAY,
+        spi: SPI,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        let panel = Ssd1680Panel::new(rst, dc, busy, delay, spi, width, height);
+        FrameBuffer::from_panel(panel, width, height)
+    }
+
+    /// Sets the custom waveforms of the refreshes, by temperature band sorted by
+    /// `max_temperature`. Before each refresh, the temperature is read (see
+    /// [`set_temperature`](Self::set_temperature)) and the waveforms of the first band covering
+    /// it are uploaded. [`refresh_fast`](Self::refresh_fast) uses the fast
+    /// waveform of the band, or its full one.
+    ///
+    /// A single band applies at every temperature, without any band (the default) the
+    /// controller uses the temperature compensated waveforms of its OTP.
+    pub fn with_waveforms(mut self, bands: &'static [TemperatureBand]) -> Self {
+        self.panel.set_waveforms(bands);
+        self
+    }
+
+    pub fn waveforms(&self) -> &'static [TemperatureBand] {
+        self.panel.waveforms()
+    }
+
+    /// See [`with_waveforms`](Self::with_waveforms).
+    pub fn set_waveforms(&mut self, bands: &'static [TemperatureBand]) {
+        self.panel.set_waveforms(bands);
+    }
+
+    pub fn temperature(&self) -> Option<i8> {
+        self.panel.temperature()
+    }
+
+    /// Sets the temperature choosing the band of [`with_waveforms`](Self::with_waveforms), in
+    /// °C, e.g. from an external sensor. `None` (the default) reads the internal sensor of the
+    /// controller before each refresh, which fails on modules whose SDA line cannot be read
+    /// back, like most 4-wire SPI breakouts without a MISO connection.
+    pub fn set_temperature(&mut self, temperature: Option<i8>) {
+        self.panel.set_temperature(temperature);
+    }
+
+    /// Reads the internal temperature sensor of the controller, in °C rounded down.
+    ///
+    /// The module must let the controller drive SDA back to the MCU, see
+    /// [`set_temperature`](Self::set_temperature) otherwise.
+    pub fn read_temperature(
+        &mut self,
+    ) -> Result<i8, DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.wait_refresh()?;
+        self.wake()?;
+        let temperature = self.panel.sense_temperature()?;
+        if self.auto_sleep() {
+            self.sleep()?;
+        }
+        Ok(temperature)
+    }
+}
+
+/// Black, white and red (or yellow) SSD1680 display.
+///
+/// The black and white plane is uploaded to the BW RAM and the red plane to the red RAM,
+/// `BUFFER_SIZE` is the size of one plane, see [`Ssd1680Display`].
+pub struct Ssd1680TriColorDisplay<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
 > {
-    driver: SSD1680<RST, DC, BUSY, DELAY, SPI>,
-    // maximum possible frame buffer size for SSD1680
-    frame_buffer: [u8; FRAME_BUFFER_SIZE],
-    width: u16,
-    height: u16,
-    refresh_count: u8,
+    display: Ssd1680Display<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>,
+    red_buffer: Plane<[u8; BUFFER_SIZE]>,
 }
 
-impl<RST: OutputPin, DC: OutputPin, BUSY: InputPin, DELAY: DelayNs, SPI: SpiDevice>
-    Ssd1680Display<RST, DC, BUSY, DELAY, SPI>
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> Ssd1680TriColorDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
 {
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the `width` x `height` resolution.
     pub fn new(
         rst: RST,
         dc: DC,
         busy: BUSY,
         delay: DELAY,
         spi: SPI,
-        config: ssd1680_rs::config::DisplayConfig,
+        width: u16,
+        height: u16,
     ) -> Self {
-        let driver = SSD1680::new(rst, dc, busy, delay, spi, config);
-        Ssd1680Display {
-            driver,
-            frame_buffer: [0; 176 * 296],
-            width: config.width,
-            height: config.height,
-            refresh_count: 10,
+        Ssd1680TriColorDisplay {
+            display: Ssd1680Display::new(rst, dc, busy, delay, spi, width, height),
+            red_buffer: Plane::new([0; BUFFER_SIZE], width, height),
         }
     }
+
+    pub fn rotation(&self) -> Rotation {
+        self.red_buffer.rotation()
+    }
+
+    /// Rotates the drawing coordinates, `width` and `height` are swapped for 90 and 270 degrees.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.display.set_rotation(rotation);
+        self.red_buffer.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.red_buffer.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.display.set_mirror(mirror);
+        self.red_buffer.set_mirror(mirror);
+    }
+
+    /// See [`Ssd1680Display::wake`].
+    pub fn wake(&mut self) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.wake()
+    }
+
+    /// See [`Ssd1680Display::sleep`].
+    pub fn sleep(&mut self) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.sleep()
+    }
+
+    pub fn is_awake(&self) -> bool {
+        self.display.is_awake()
+    }
+
+    pub fn auto_sleep(&self) -> bool {
+        self.display.auto_sleep()
+    }
+
+    /// See [`Ssd1680Display::set_auto_sleep`].
+    pub fn set_auto_sleep(&mut self, auto_sleep: bool) {
+        self.display.set_auto_sleep(auto_sleep);
+    }
 }
-impl<RST: OutputPin, DC: OutputPin, BUSY: InputPin, DELAY: DelayNs, SPI: SpiDevice> ErrorType
-    for Ssd1680Display<RST, DC, BUSY, DELAY, SPI>
+
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> ErrorType for Ssd1680TriColorDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
 {
-    type Error = ssd1680_rs::error::Error<SPI::Error, RST::Error, DC::Error, BUSY::Error>;
+    type Error = DisplayError<DriverError<RST, DC, BUSY, SPI>>;
 }
 
 impl<
@@ -63,119 +541,449 @@ impl<
     R: Debug,
     D: Debug,
     B: Debug,
-> BWDisplay for Ssd1680Display<RST, DC, BUSY, DELAY, SPI>
+    const BUFFER_SIZE: usize,
+> TriColorDisplay for Ssd1680TriColorDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
 where
     SPI: SpiDevice<Error = S>,
     RST: OutputPin<Error = R>,
     DC: OutputPin<Error = D>,
     BUSY: InputPin<Error = B>,
 {
-    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Error<S, R, D, B>> {
-        let address = get_address(x, y, self.width);
-        self.frame_buffer[address.buffer_position] = (self.frame_buffer[address.buffer_position]
-            & !(1 << address.byte_offset))
-            | ((color as u8) << address.byte_offset);
-        Ok(())
+    fn width(&self) -> u16 {
+        self.red_buffer.width()
     }
 
-    fn fill(&mut self, color: bool) -> Result<(), Error<S, R, D, B>> {
-        self.frame_buffer = [(color as u8) * 255; FRAME_BUFFER_SIZE];
-        Ok(())
+    fn height(&self) -> u16 {
+        self.red_buffer.height()
+    }
+
+    fn set_pixel(
+        &mut self,
+        x: u16,
+        y: u16,
+        color: TriColor,
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        // red pixels are left white in the black and white plane
+        self.display
+            .frames
+            .frame_buffer
+            .set_pixel(x, y, color != TriColor::Black)?;
+        self.red_buffer.set_pixel(x, y, color == TriColor::Red)
     }
 
-    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Error<S, R, D, B>> {
-        self.frame_buffer.copy_from_slice(buffer);
+    fn get_pixel(&self, x: u16, y: u16) -> Result<TriColor, DisplayError<Error<S, R, D, B>>> {
+        if self.red_buffer.get_pixel(x, y)? {
+            Ok(TriColor::Red)
+        } else if self.display.frames.frame_buffer.get_pixel(x, y)? {
+            Ok(TriColor::White)
+        } else {
+            Ok(TriColor::Black)
+        }
+    }
+
+    fn fill(&mut self, color: TriColor) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display
+            .frames
+            .frame_buffer
+            .fill(color != TriColor::Black);
+        self.red_buffer.fill(color == TriColor::Red);
         Ok(())
     }
 
     fn draw_buffer(
         &mut self,
-        buffer: &[u8],
+        bw_buffer: &[u8],
+        red_buffer: &[u8],
         x: u16,
         y: u16,
         w: u16,
         h: u16,
-    ) -> Result<(), Error<S, R, D, B>> {
-        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.frames.frame_buffer.draw_buffer(
+            bw_buffer,
+            x,
+            y,
+            w,
+            h,
+            TransparencySetting::None,
+        )?;
+        self.red_buffer
+            .draw_buffer(red_buffer, x, y, w, h, TransparencySetting::None)
     }
 
-    fn draw_buffer_with_transparency(
+    fn refresh(&mut self) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        // tri-color panels only support the full refresh waveform
+        self.display.refresh_planes(self.red_buffer.buffer())?;
+        self.red_buffer.clear_dirty();
+        Ok(())
+    }
+}
+
+/// SSD1680 display with 4 gray levels, for panels driven with a gray waveform.
+///
+/// Gray frames are split in two planes: the high bit of each pixel goes to the BW RAM and the low
+/// bit to the red RAM, then [`refresh_gray`](Self::refresh_gray) runs the gray waveform on them.
+/// The display also implements [`BWDisplay`], with the usual partial and full refreshes, where
+/// white and black are the lightest and darkest levels. `BUFFER_SIZE` is the size of one plane,
+/// see [`Ssd1680Display`].
+pub struct Ssd1680GrayscaleDisplay<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> {
+    display: Ssd1680Display<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>,
+    low_bits: Plane<[u8; BUFFER_SIZE]>,
+    waveform: &'static Waveform,
+    // whether the panel shows a gray frame, which partial refreshes cannot start from
+    gray_shown: bool,
+}
+
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> Ssd1680GrayscaleDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
+{
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the `width` x `height` resolution.
+    pub fn new(
+        rst: RST,
+        dc: DC,
+        busy: BUSY,
+        delay: DELAY,
+        spi: SPI,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        Ssd1680GrayscaleDisplay {
+            display: Ssd1680Display::new(rst, dc, busy, delay, spi, width, height),
+            low_bits: Plane::new([0; BUFFER_SIZE], width, height),
+            waveform: &GRAY4_WAVEFORM,
+            gray_shown: false,
+        }
+    }
+
+    /// Sets the waveform of the gray refreshes, [`GRAY4_WAVEFORM`] otherwise.
+    pub fn with_gray_waveform(mut self, waveform: &'static Waveform) -> Self {
+        self.waveform = waveform;
+        self
+    }
+
+    pub fn set_gray_pixel(
+        &mut self,
+        x: u16,
+        y: u16,
+        level: Gray4,
+    ) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        let bits = level.bits();
+        self.display
+            .frames
+            .frame_buffer
+            .set_pixel(x, y, bits & 0b10 != 0)?;
+        self.low_bits.set_pixel(x, y, bits & 0b01 != 0)
+    }
+
+    pub fn get_gray_pixel(
+        &self,
+        x: u16,
+        y: u16,
+    ) -> Result<Gray4, DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        let high = self.display.frames.frame_buffer.get_pixel(x, y)?;
+        let low = self.low_bits.get_pixel(x, y)?;
+        Ok(Gray4::from_bits((high as u8) << 1 | low as u8))
+    }
+
+    pub fn fill_gray(&mut self, level: Gray4) {
+        let bits = level.bits();
+        self.display.frames.frame_buffer.fill(bits & 0b10 != 0);
+        self.low_bits.fill(bits & 0b01 != 0);
+    }
+
+    /// Replaces the whole frame with the 2 bits per pixel `buffer`, which must be exactly
+    /// [`gray_buffer_size`] bytes long. It is copied in the native orientation of the panel.
+    pub fn set_gray_buffer(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        let full_area = self.low_bits.full_area();
+        if buffer.len() != gray_buffer_size(full_area.w, full_area.h) {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        let (rotation, mirror) = (self.rotation(), self.mirror());
+        self.set_rotation(Rotation::Rotate0);
+        self.set_mirror(Mirror::None);
+        let result = self.draw_gray_buffer(buffer, 0, 0, full_area.w, full_area.h);
+        self.set_rotation(rotation);
+        self.set_mirror(mirror);
+        result
+    }
+
+    /// Draws the `w` x `h` 2 bits per pixel `buffer` with its top left corner at (`x`, `y`).
+    /// Rows of `buffer` are padded to a whole number of bytes.
+    pub fn draw_gray_buffer(
         &mut self,
         buffer: &[u8],
         x: u16,
         y: u16,
         w: u16,
         h: u16,
-        transparency: TransparencySetting,
-    ) -> Result<(), Error<S, R, D, B>> {
-        for j in 0..h {
-            for i in 0..(w / 8) + 1 {
-                let address_frambuffer_byte = get_address(x + i * 8, y + j, self.width);
-                let address_buffer_byte = get_address(i * 8, j, w);
-
-                let mut framebuffer_byte =
-                    self.frame_buffer[address_frambuffer_byte.buffer_position];
-                let offset = address_frambuffer_byte.byte_offset;
-                if i != 0 && offset != 0 {
-                    let previous_byte = buffer[address_buffer_byte.buffer_position - 1];
-
-                    match transparency {
-                        TransparencySetting::None => {
-                            framebuffer_byte &= 0xff_u8.checked_shr(offset.into()).unwrap_or(0);
-                            framebuffer_byte |= previous_byte << (8 - offset);
-                        }
-                        _ => {
-                            unimplemented!()
-                        }
-                    }
-                }
-                if i < (w / 8) {
-                    let current_byte = buffer[address_buffer_byte.buffer_position];
-
-                    match transparency {
-                        TransparencySetting::None => {
-                            framebuffer_byte &=
-                                0xff_u8.checked_shl((8 - offset).into()).unwrap_or(0);
-                            framebuffer_byte |= current_byte >> offset;
-                        }
-                        _ => {
-                            unimplemented!()
-                        }
-                    }
-                }
+    ) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        if x >= self.low_bits.width() || y >= self.low_bits.height() {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let stride = w.div_ceil(4) as usize;
+        if buffer.len() < stride * h as usize {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        let visible_w = w.min(self.low_bits.width() - x) as usize;
+        let visible_h = h.min(self.low_bits.height() - y);
 
-                self.frame_buffer[address_frambuffer_byte.buffer_position] = framebuffer_byte;
-            }
+        // each row is split in the two planes
+        for j in 0..visible_h {
+            let row = &buffer[j as usize * stride..];
+            let bits = |pixel: usize| row[pixel / 4] >> (6 - 2 * (pixel % 4));
+            draw_chunks(
+                0..visible_w,
+                |pixel| bits(pixel) & 0b10 != 0,
+                |chunk, pixel, len| {
+                    self.display.frames.frame_buffer.draw_buffer(
+                        chunk,
+                        x + pixel as u16,
+                        y + j,
+                        len as u16,
+                        1,
+                        TransparencySetting::None,
+                    )
+                },
+            )?;
+            draw_chunks(
+                0..visible_w,
+                |pixel| bits(pixel) & 0b01 != 0,
+                |chunk, pixel, len| {
+                    self.low_bits.draw_buffer(
+                        chunk,
+                        x + pixel as u16,
+                        y + j,
+                        len as u16,
+                        1,
+                        TransparencySetting::None,
+                    )
+                },
+            )?;
         }
+        Ok(())
+    }
 
+    /// Uploads both planes and refreshes the panel with the gray waveform.
+    ///
+    /// The next [`BWDisplay::refresh`] is a full refresh, which also restores the waveform of
+    /// the panel.
+    pub fn refresh_gray(&mut self) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.panel.forced_waveform = Some(self.waveform);
+        let shown = self.display.refresh_planes(self.low_bits.buffer());
+        self.display.panel.forced_waveform = None;
+        shown?;
+        self.low_bits.clear_dirty();
+        self.gray_shown = true;
         Ok(())
     }
 
-    fn refresh(&mut self, force_full: bool) -> Result<(), Error<S, R, D, B>> {
-        self.driver.hw_init()?;
-        self.driver
-            .write_bw_bytes(&self.frame_buffer[0..(self.height * self.width / 8) as usize])?;
-        if self.refresh_count >= 5 || force_full {
-            self.driver.full_refresh()?;
-            self.refresh_count = 0;
-        } else {
-            self.driver.partial_refresh()?;
-            self.refresh_count += 1;
-        }
-        self.driver.enter_deep_sleep()
+    pub fn rotation(&self) -> Rotation {
+        self.low_bits.rotation()
+    }
+
+    /// Rotates the drawing coordinates, `width` and `height` are swapped for 90 and 270 degrees.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.display.set_rotation(rotation);
+        self.low_bits.set_rotation(rotation);
+    }
+
+    pub fn mirror(&self) -> Mirror {
+        self.low_bits.mirror()
+    }
+
+    pub fn set_mirror(&mut self, mirror: Mirror) {
+        self.display.set_mirror(mirror);
+        self.low_bits.set_mirror(mirror);
+    }
+
+    /// See [`Ssd1680Display::wake`].
+    pub fn wake(&mut self) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.wake()
+    }
+
+    /// See [`Ssd1680Display::sleep`].
+    pub fn sleep(&mut self) -> Result<(), DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.sleep()
+    }
+
+    pub fn is_awake(&self) -> bool {
+        self.display.is_awake()
+    }
+
+    pub fn auto_sleep(&self) -> bool {
+        self.display.auto_sleep()
+    }
+
+    /// See [`Ssd1680Display::set_auto_sleep`].
+    pub fn set_auto_sleep(&mut self, auto_sleep: bool) {
+        self.display.set_auto_sleep(auto_sleep);
+    }
+
+    /// See [`Ssd1680Display::set_refresh_policy`].
+    pub fn set_refresh_policy(&mut self, policy: RefreshPolicy) {
+        self.display.set_refresh_policy(policy);
+    }
+
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        self.display.refresh_policy()
+    }
+
+    /// See [`Ssd1680Display::with_waveforms`].
+    pub fn set_waveforms(&mut self, bands: &'static [TemperatureBand]) {
+        self.display.set_waveforms(bands);
+    }
+
+    /// See [`Ssd1680Display::read_temperature`].
+    pub fn read_temperature(
+        &mut self,
+    ) -> Result<i8, DisplayError<DriverError<RST, DC, BUSY, SPI>>> {
+        self.display.read_temperature()
     }
 }
 
-struct Address {
-    pub buffer_position: usize,
-    pub byte_offset: u8,
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> ErrorType for Ssd1680GrayscaleDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
+{
+    type Error = DisplayError<DriverError<RST, DC, BUSY, SPI>>;
 }
 
-fn get_address(x: u16, y: u16, width: u16) -> Address {
-    let frambuffer_position = (x / 8 + y * width / 8) as usize;
-    let byte_offset = (x % 8) as u8;
-    Address {
-        buffer_position: frambuffer_position,
-        byte_offset,
+/// Black and white drawing, on both planes so that the pixels stay black or white in gray
+/// refreshes.
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    S: Debug,
+    R: Debug,
+    D: Debug,
+    B: Debug,
+    const BUFFER_SIZE: usize,
+> BWDraw for Ssd1680GrayscaleDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
+where
+    SPI: SpiDevice<Error = S>,
+    RST: OutputPin<Error = R>,
+    DC: OutputPin<Error = D>,
+    BUSY: InputPin<Error = B>,
+{
+    fn width(&self) -> u16 {
+        self.low_bits.width()
+    }
+
+    fn height(&self) -> u16 {
+        self.low_bits.height()
+    }
+
+    fn set_pixel(
+        &mut self,
+        x: u16,
+        y: u16,
+        color: bool,
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.set_pixel(x, y, color)?;
+        self.low_bits.set_pixel(x, y, color)
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, DisplayError<Error<S, R, D, B>>> {
+        self.display.get_pixel(x, y)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.fill(color)?;
+        self.low_bits.fill(color);
+        Ok(())
+    }
+
+    fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        color: bool,
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.fill_rect(x, y, w, h, color)?;
+        self.low_bits.fill_rect(x, y, w, h, color)
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.set_buffer(buffer)?;
+        self.low_bits.set_buffer(buffer)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display
+            .draw_buffer_with_transparency(buffer, x, y, w, h, transparency)?;
+        self.low_bits.draw_buffer(buffer, x, y, w, h, transparency)
+    }
+}
+
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: InputPin,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    S: Debug,
+    R: Debug,
+    D: Debug,
+    B: Debug,
+    const BUFFER_SIZE: usize,
+> BWDisplay for Ssd1680GrayscaleDisplay<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
+where
+    SPI: SpiDevice<Error = S>,
+    RST: OutputPin<Error = R>,
+    DC: OutputPin<Error = D>,
+    BUSY: InputPin<Error = B>,
+{
+    /// Refreshes the black and white plane, fully after a gray refresh.
+    fn refresh(&mut self, force_full: bool) -> Result<(), DisplayError<Error<S, R, D, B>>> {
+        self.display.refresh(force_full || self.gray_shown)?;
+        self.gray_shown = false;
+        Ok(())
     }
 }
@@ -0,0 +1,178 @@
+//! SSD1680 display driven with the `embedded-hal-async` traits.
+//!
+//! The controller is driven with the same commands as
+//! [`Ssd1680Display`](crate::ssd1680::Ssd1680Display), but the refreshes await the BUSY pin with
+//! [`Wait`], so the executor runs other tasks while the panel updates.
+
+use embedded_hal::digital::OutputPin;
+use embedded_hal_async::{delay::DelayNs, digital::Wait, spi::SpiDevice};
+
+pub use super::ssd1680_protocol::Error;
+use super::{
+    framebuffer::{Area, RamPlane, RefreshMode},
+    framebuffer_async::{AsyncFrameBuffer, AsyncPanelDriver},
+    ssd1680_protocol::{
+        Command, FULL_UPDATE, PARTIAL_UPDATE, RESET_DELAY_MS, activation_commands, command,
+        deep_sleep_command, init_commands, window_commands,
+    },
+};
+
+/// Error of the controller of an [`AsyncSsd1680Display`].
+type DriverError<RST, DC, BUSY, SPI> = Error<
+    <SPI as embedded_hal_async::spi::ErrorType>::Error,
+    <RST as embedded_hal::digital::ErrorType>::Error,
+    <DC as embedded_hal::digital::ErrorType>::Error,
+    <BUSY as embedded_hal::digital::ErrorType>::Error,
+>;
+
+/// [`AsyncPanelDriver`] of the SSD1680, writing the frame to the BW RAM and the reference of
+/// partial refreshes to the red RAM.
+pub struct AsyncSsd1680Panel<RST, DC, BUSY, DELAY, SPI> {
+    rst: RST,
+    dc: DC,
+    busy: BUSY,
+    delay: DELAY,
+    spi: SPI,
+    // whole RAM, in buffer coordinates
+    full_area: Area,
+    // RAM plane written by the last command, whose writes can go on without a new command
+    writing: Option<RamPlane>,
+}
+
+impl<RST: OutputPin, DC: OutputPin, BUSY: Wait, DELAY: DelayNs, SPI: SpiDevice>
+    AsyncSsd1680Panel<RST, DC, BUSY, DELAY, SPI>
+{
+    /// Creates the driver of a `width` x `height` panel, in its native orientation.
+    pub fn new(
+        rst: RST,
+        dc: DC,
+        busy: BUSY,
+        delay: DELAY,
+        spi: SPI,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        AsyncSsd1680Panel {
+            rst,
+            dc,
+            busy,
+            delay,
+            spi,
+            full_area: Area {
+                x: 0,
+                y: 0,
+                w: width,
+                h: height,
+            },
+            writing: None,
+        }
+    }
+
+    async fn send_command(&mut self, command: u8) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.writing = None;
+        self.dc.set_low().map_err(Error::DataCommand)?;
+        self.spi.write(&[command]).await.map_err(Error::Spi)
+    }
+
+    async fn send_data(&mut self, data: &[u8]) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        self.dc.set_high().map_err(Error::DataCommand)?;
+        self.spi.write(data).await.map_err(Error::Spi)
+    }
+
+    async fn send(&mut self, commands: &[Command]) -> Result<(), DriverError<RST, DC, BUSY, SPI>> {
+        for command in commands {
+            self.send_command(command.code).await?;
+            if !command.data().is_empty() {
+                self.send_data(command.data()).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<RST: OutputPin, DC: OutputPin, BUSY: Wait, DELAY: DelayNs, SPI: SpiDevice> AsyncPanelDriver
+    for AsyncSsd1680Panel<RST, DC, BUSY, DELAY, SPI>
+{
+    type Error = DriverError<RST, DC, BUSY, SPI>;
+
+    /// Hardware and software reset followed by the configuration of the panel geometry.
+    async fn init(&mut self) -> Result<(), Self::Error> {
+        self.rst.set_low().map_err(Error::Reset)?;
+        self.delay.delay_ms(RESET_DELAY_MS).await;
+        self.rst.set_high().map_err(Error::Reset)?;
+        self.delay.delay_ms(RESET_DELAY_MS).await;
+        self.wait_until_idle().await?;
+        self.send_command(command::SW_RESET).await?;
+        self.wait_until_idle().await?;
+        self.send(&init_commands(self.full_area)).await?;
+        self.wait_until_idle().await
+    }
+
+    async fn set_window(&mut self, area: Area) -> Result<(), Self::Error> {
+        self.send(&window_commands(area)).await
+    }
+
+    async fn write_plane(&mut self, plane: RamPlane, data: &[u8]) -> Result<(), Self::Error> {
+        if self.writing != Some(plane) {
+            self.send_command(match plane {
+                RamPlane::Frame => command::WRITE_BW_RAM,
+                RamPlane::Reference => command::WRITE_RED_RAM,
+            })
+            .await?;
+            self.writing = Some(plane);
+        }
+        // the controller keeps its RAM address between data transfers
+        self.send_data(data).await
+    }
+
+    /// Runs the waveform of the OTP, fast refreshes run the full one.
+    async fn refresh(&mut self, mode: RefreshMode) -> Result<(), Self::Error> {
+        let sequence = if mode == RefreshMode::Partial {
+            PARTIAL_UPDATE
+        } else {
+            FULL_UPDATE
+        };
+        self.send(&activation_commands(sequence)).await
+    }
+
+    async fn wait_until_idle(&mut self) -> Result<(), Self::Error> {
+        self.busy.wait_for_low().await.map_err(Error::Busy)
+    }
+
+    async fn sleep(&mut self) -> Result<(), Self::Error> {
+        self.send(&[deep_sleep_command()]).await
+    }
+}
+
+/// Black and white SSD1680 display refreshed asynchronously.
+///
+/// It behaves like [`Ssd1680Display`](crate::ssd1680::Ssd1680Display): `BUFFER_SIZE` must be
+/// [`buffer_size`](crate::buffer_size)`(width, height)` of the panel, see [`AsyncFrameBuffer`].
+pub type AsyncSsd1680Display<RST, DC, BUSY, DELAY, SPI, const BUFFER_SIZE: usize> =
+    AsyncFrameBuffer<AsyncSsd1680Panel<RST, DC, BUSY, DELAY, SPI>, BUFFER_SIZE>;
+
+impl<
+    RST: OutputPin,
+    DC: OutputPin,
+    BUSY: Wait,
+    DELAY: DelayNs,
+    SPI: SpiDevice,
+    const BUFFER_SIZE: usize,
+> AsyncSsd1680Display<RST, DC, BUSY, DELAY, SPI, BUFFER_SIZE>
+{
+    /// # Panics
+    ///
+    /// Panics if `BUFFER_SIZE` does not match the `width` x `height` resolution.
+    pub fn new(
+        rst: RST,
+        dc: DC,
+        busy: BUSY,
+        delay: DELAY,
+        spi: SPI,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        let panel = AsyncSsd1680Panel::new(rst, dc, busy, delay, spi, width, height);
+        AsyncFrameBuffer::from_panel(panel, width, height)
+    }
+}
@@ -0,0 +1,137 @@
+//! SSD1680 commands shared by the blocking and the async displays.
+//!
+//! The sequences are built as [`Command`] arrays, sent over the bus by each display.
+
+use crate::plane::Area;
+
+/// SSD1680 commands.
+// the waveform and temperature commands are only sent by the blocking display
+#[cfg_attr(not(feature = "ssd1680"), allow(dead_code))]
+pub(crate) mod command {
+    pub const DRIVER_OUTPUT_CONTROL: u8 = 0x01;
+    pub const GATE_DRIVING_VOLTAGE: u8 = 0x03;
+    pub const SOURCE_DRIVING_VOLTAGE: u8 = 0x04;
+    pub const DEEP_SLEEP_MODE: u8 = 0x10;
+    pub const DATA_ENTRY_MODE: u8 = 0x11;
+    pub const SW_RESET: u8 = 0x12;
+    pub const TEMPERATURE_SENSOR_CONTROL: u8 = 0x18;
+    pub const READ_TEMPERATURE_REGISTER: u8 = 0x1B;
+    pub const MASTER_ACTIVATION: u8 = 0x20;
+    pub const DISPLAY_UPDATE_CONTROL_2: u8 = 0x22;
+    pub const WRITE_BW_RAM: u8 = 0x24;
+    pub const WRITE_RED_RAM: u8 = 0x26;
+    pub const WRITE_VCOM_REGISTER: u8 = 0x2C;
+    pub const WRITE_LUT_REGISTER: u8 = 0x32;
+    pub const BORDER_WAVEFORM_CONTROL: u8 = 0x3C;
+    pub const END_OPTION: u8 = 0x3F;
+    pub const SET_RAM_X_ADDRESS_START_END: u8 = 0x44;
+    pub const SET_RAM_Y_ADDRESS_START_END: u8 = 0x45;
+    pub const SET_RAM_X_ADDRESS_COUNTER: u8 = 0x4E;
+    pub const SET_RAM_Y_ADDRESS_COUNTER: u8 = 0x4F;
+}
+
+/// Display update sequences loading the waveform of the OTP, in display mode 1 (full) and 2
+/// (partial).
+pub(crate) const FULL_UPDATE: u8 = 0xF7;
+pub(crate) const PARTIAL_UPDATE: u8 = 0xFF;
+/// Selects the internal temperature sensor.
+pub(crate) const INTERNAL_TEMPERATURE_SENSOR: u8 = 0x80;
+/// Deep sleep mode 1, left by a hardware reset.
+pub(crate) const DEEP_SLEEP_MODE_1: u8 = 0x01;
+/// Duration of the reset pulse and of the wait after it.
+pub(crate) const RESET_DELAY_MS: u32 = 10;
+
+/// Error of the SPI bus or of a pin of the controller, reported by the SSD1680 displays as
+/// [`DisplayError::Driver`](crate::DisplayError::Driver).
+#[derive(Debug)]
+pub enum Error<S, R, D, B> {
+    Spi(S),
+    Reset(R),
+    DataCommand(D),
+    Busy(B),
+}
+
+/// Command with up to 4 bytes of data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Command {
+    pub code: u8,
+    data: [u8; 4],
+    len: u8,
+}
+
+impl Command {
+    const fn new(code: u8, data: &[u8]) -> Self {
+        let mut bytes = [0; 4];
+        let mut i = 0;
+        while i < data.len() {
+            bytes[i] = data[i];
+            i += 1;
+        }
+        Command {
+            code,
+            data: bytes,
+            len: data.len() as u8,
+        }
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+}
+
+/// Configuration of the panel geometry, sent after the software reset with a window covering
+/// the whole `area`.
+pub(crate) fn init_commands(area: Area) -> [Command; 8] {
+    let [gates_low, gates_high] = (area.h - 1).to_le_bytes();
+    let [x_start_end, y_start_end, x_counter, y_counter] = window_commands(area);
+    [
+        Command::new(
+            command::DRIVER_OUTPUT_CONTROL,
+            &[gates_low, gates_high, 0x00],
+        ),
+        // x and y increment
+        Command::new(command::DATA_ENTRY_MODE, &[0x03]),
+        x_start_end,
+        y_start_end,
+        x_counter,
+        y_counter,
+        Command::new(command::BORDER_WAVEFORM_CONTROL, &[0x05]),
+        Command::new(
+            command::TEMPERATURE_SENSOR_CONTROL,
+            &[INTERNAL_TEMPERATURE_SENSOR],
+        ),
+    ]
+}
+
+/// Restricts the RAM writes to the bytes containing `area` and moves the RAM address to its
+/// top left.
+pub(crate) fn window_commands(area: Area) -> [Command; 4] {
+    let x_start = (area.x / 8) as u8;
+    let x_end = ((area.x + area.w - 1) / 8) as u8;
+    let [y_start_low, y_start_high] = area.y.to_le_bytes();
+    let [y_end_low, y_end_high] = (area.y + area.h - 1).to_le_bytes();
+    [
+        Command::new(command::SET_RAM_X_ADDRESS_START_END, &[x_start, x_end]),
+        Command::new(
+            command::SET_RAM_Y_ADDRESS_START_END,
+            &[y_start_low, y_start_high, y_end_low, y_end_high],
+        ),
+        Command::new(command::SET_RAM_X_ADDRESS_COUNTER, &[x_start]),
+        Command::new(
+            command::SET_RAM_Y_ADDRESS_COUNTER,
+            &[y_start_low, y_start_high],
+        ),
+    ]
+}
+
+/// Starts the display update `sequence`, the BUSY pin is high until its end.
+pub(crate) fn activation_commands(sequence: u8) -> [Command; 2] {
+    [
+        Command::new(command::DISPLAY_UPDATE_CONTROL_2, &[sequence]),
+        Command::new(command::MASTER_ACTIVATION, &[]),
+    ]
+}
+
+pub(crate) fn deep_sleep_command() -> Command {
+    Command::new(command::DEEP_SLEEP_MODE, &[DEEP_SLEEP_MODE_1])
+}
@@ -0,0 +1,46 @@
+use e_ink_graphics_library::{
+    BWDraw, buffer_size, canvas::Canvas, copy_reversed_bit_order, reverse_bit_order,
+};
+
+#[test]
+fn reverse_bit_order_mirrors_each_byte() {
+    let mut buffer = [0x01, 0x80, 0b1100_1010, 0x0f, 0xff];
+    reverse_bit_order(&mut buffer);
+    assert_eq!(buffer, [0x80, 0x01, 0b0101_0011, 0xf0, 0xff]);
+    reverse_bit_order(&mut buffer);
+    assert_eq!(buffer, [0x01, 0x80, 0b1100_1010, 0x0f, 0xff]);
+}
+
+#[test]
+fn copy_reversed_bit_order_stops_at_the_shortest_buffer() {
+    let mut destination = [0xaa; 2];
+    copy_reversed_bit_order(&[0x01, 0x03, 0x07], &mut destination);
+    assert_eq!(destination, [0x80, 0xc0]);
+
+    let mut destination = [0xaa; 3];
+    copy_reversed_bit_order(&[0x01, 0x03], &mut destination);
+    assert_eq!(destination, [0x80, 0xc0, 0xaa]);
+}
+
+#[test]
+fn leftmost_pixel_is_the_most_significant_bit() {
+    // 10 pixels wide rows padded to 2 bytes
+    let mut canvas = Canvas::new([0xff; buffer_size(10, 2)], 10, 2);
+    canvas
+        .set_buffer(&[0b0111_1111, 0b1011_1111, 0b1111_1110, 0b0111_1111])
+        .unwrap();
+    let black_pixels = |canvas: &Canvas<[u8; 4]>, y| {
+        (0..10)
+            .filter(|x| !canvas.get_pixel(*x, y).unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(black_pixels(&canvas, 0), [0, 9]);
+    assert_eq!(black_pixels(&canvas, 1), [7, 8]);
+
+    canvas.set_pixel(1, 1, false).unwrap();
+    canvas.set_pixel(9, 0, true).unwrap();
+    assert_eq!(
+        canvas.buffer(),
+        [0b0111_1111, 0b1111_1111, 0b1011_1110, 0b0111_1111]
+    );
+}
@@ -0,0 +1,135 @@
+use std::convert::Infallible;
+
+use e_ink_graphics_library::{
+    BWDraw, DisplayError, ErrorType, TransparencySetting, buffer_size, pattern::Pattern,
+};
+
+const WIDTH: u16 = 10;
+const HEIGHT: u16 = 6;
+
+/// Display implementing only the required methods pixel by pixel, to check the provided ones.
+struct Pixels([[bool; WIDTH as usize]; HEIGHT as usize]);
+
+impl ErrorType for Pixels {
+    type Error = DisplayError<Infallible>;
+}
+
+impl BWDraw for Pixels {
+    fn width(&self) -> u16 {
+        WIDTH
+    }
+
+    fn height(&self) -> u16 {
+        HEIGHT
+    }
+
+    fn set_pixel(&mut self, x: u16, y: u16, color: bool) -> Result<(), Self::Error> {
+        *self
+            .0
+            .get_mut(y as usize)
+            .and_then(|row| row.get_mut(x as usize))
+            .ok_or(DisplayError::OutOfBounds)? = color;
+        Ok(())
+    }
+
+    fn get_pixel(&self, x: u16, y: u16) -> Result<bool, Self::Error> {
+        self.0
+            .get(y as usize)
+            .and_then(|row| row.get(x as usize))
+            .copied()
+            .ok_or(DisplayError::OutOfBounds)
+    }
+
+    fn fill(&mut self, color: bool) -> Result<(), Self::Error> {
+        self.0 = [[color; WIDTH as usize]; HEIGHT as usize];
+        Ok(())
+    }
+
+    fn set_buffer(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        if buffer.len() != buffer_size(WIDTH, HEIGHT) {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        self.draw_buffer(buffer, 0, 0, WIDTH, HEIGHT)
+    }
+
+    fn draw_buffer(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+    ) -> Result<(), Self::Error> {
+        self.draw_buffer_with_transparency(buffer, x, y, w, h, TransparencySetting::None)
+    }
+
+    fn draw_buffer_with_transparency(
+        &mut self,
+        buffer: &[u8],
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        transparency: TransparencySetting,
+    ) -> Result<(), Self::Error> {
+        if x >= WIDTH || y >= HEIGHT {
+            return Err(DisplayError::OutOfBounds);
+        }
+        let stride = w.div_ceil(8) as usize;
+        if buffer.len() < stride * h as usize {
+            return Err(DisplayError::InvalidBufferLength);
+        }
+        for j in 0..h.min(HEIGHT - y) {
+            for i in 0..w.min(WIDTH - x) {
+                let color = buffer[j as usize * stride + i as usize / 8] & (0x80 >> (i % 8)) != 0;
+                let transparent = match transparency {
+                    TransparencySetting::None => false,
+                    TransparencySetting::BlackTransparent => !color,
+                    TransparencySetting::WhiteTransparent => color,
+                };
+                if !transparent {
+                    self.set_pixel(x + i, y + j, color)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn provided_fill_rect_clips_at_the_edges() {
+    let mut display = Pixels([[true; WIDTH as usize]; HEIGHT as usize]);
+    display.fill_rect(7, 4, u16::MAX, u16::MAX, false).unwrap();
+
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let inside = x >= 7 && y >= 4;
+            assert_eq!(display.get_pixel(x, y).unwrap(), !inside, "({x}, {y})");
+        }
+    }
+    assert!(matches!(
+        display.fill_rect(WIDTH, 0, 1, 1, false),
+        Err(DisplayError::OutOfBounds)
+    ));
+    assert!(matches!(
+        display.fill_rect(0, HEIGHT, 1, 1, false),
+        Err(DisplayError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn provided_fill_pattern_tiles_the_display() {
+    let mut display = Pixels([[true; WIDTH as usize]; HEIGHT as usize]);
+    let pattern = Pattern::FORWARD_DIAGONAL;
+    display.fill_pattern(pattern).unwrap();
+
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            assert_eq!(
+                display.get_pixel(x, y).unwrap(),
+                pattern.color_at(x, y),
+                "({x}, {y})"
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use e_ink_graphics_library::{BWDraw, TransparencySetting, buffer_size, canvas::Canvas};
+
+#[test]
+fn canvas_is_drawn_onto_another_target() {
+    let mut sprite = Canvas::new([0xff; buffer_size(4, 2)], 4, 2);
+    sprite.set_pixel(0, 0, false).unwrap();
+    sprite.set_pixel(3, 1, false).unwrap();
+
+    let mut storage = [0xff; buffer_size(16, 4)];
+    let mut screen = Canvas::new(&mut storage[..], 16, 4);
+    screen.fill_rect(0, 0, 16, 2, false).unwrap();
+    sprite
+        .draw_to(&mut screen, 2, 1, TransparencySetting::WhiteTransparent)
+        .unwrap();
+
+    // white pixels of the sprite leave the black rows of the screen untouched
+    assert!(!screen.get_pixel(3, 1).unwrap());
+    assert!(!screen.get_pixel(2, 1).unwrap());
+    assert!(!screen.get_pixel(5, 2).unwrap());
+    assert!(screen.get_pixel(4, 2).unwrap());
+    assert_eq!(screen.into_buffer()[4..6], [0xfb, 0xff]);
+}
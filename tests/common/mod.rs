@@ -0,0 +1,331 @@
+//! Recording mocks of the embedded-hal traits used by the displays.
+// each test crate uses its own part of the mocks
+#![allow(dead_code)]
+
+use std::{
+    cell::{Cell, RefCell},
+    convert::Infallible,
+    rc::Rc,
+};
+
+#[cfg(feature = "async")]
+use e_ink_graphics_library::ssd1680_async::AsyncSsd1680Display;
+use e_ink_graphics_library::{
+    buffer_size,
+    ssd1680::{Ssd1680Display, Ssd1680GrayscaleDisplay, Ssd1680TriColorDisplay},
+};
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{self, InputPin, OutputPin},
+    spi::{self, Operation, SpiDevice},
+};
+
+pub const WIDTH: u16 = 16;
+pub const HEIGHT: u16 = 8;
+pub const BUFFER_SIZE: usize = buffer_size(WIDTH, HEIGHT);
+
+pub type TestDisplay =
+    Ssd1680Display<MockOutputPin, MockOutputPin, MockBusy, MockDelay, MockSpi, BUFFER_SIZE>;
+pub type TestTriColorDisplay =
+    Ssd1680TriColorDisplay<MockOutputPin, MockOutputPin, MockBusy, MockDelay, MockSpi, BUFFER_SIZE>;
+#[cfg(feature = "async")]
+pub type TestAsyncDisplay =
+    AsyncSsd1680Display<MockOutputPin, MockOutputPin, MockBusy, MockDelay, MockSpi, BUFFER_SIZE>;
+pub type TestGrayscaleDisplay = Ssd1680GrayscaleDisplay<
+    MockOutputPin,
+    MockOutputPin,
+    MockBusy,
+    MockDelay,
+    MockSpi,
+    BUFFER_SIZE,
+>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pin {
+    Reset,
+    DataCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Pin(Pin, bool),
+    Spi(Vec<u8>),
+    /// Level of the BUSY pin read by the display.
+    Busy(bool),
+}
+
+const MASTER_ACTIVATION: u8 = 0x20;
+
+/// Events recorded by the mocks, in order, and the state of the mock controller.
+#[derive(Debug, Clone, Default)]
+pub struct Log {
+    events: Rc<RefCell<Vec<Event>>>,
+    // BUSY polls reporting a running update after each master activation
+    update_polls: Rc<Cell<u32>>,
+    // BUSY polls left before the running update ends
+    busy_polls: Rc<Cell<u32>>,
+    data_mode: Rc<Cell<bool>>,
+}
+
+impl Log {
+    fn push(&self, event: Event) {
+        match event {
+            Event::Pin(Pin::DataCommand, level) => self.data_mode.set(level),
+            Event::Spi(ref bytes)
+                if !self.data_mode.get() && bytes.contains(&MASTER_ACTIVATION) =>
+            {
+                self.busy_polls.set(self.update_polls.get());
+            }
+            _ => {}
+        }
+        self.events.borrow_mut().push(event);
+    }
+
+    /// Reads the BUSY pin, which stays high for the polls set by `set_update_polls`.
+    fn poll_busy(&self) -> bool {
+        let busy = self.busy_polls.get() > 0;
+        if busy {
+            self.busy_polls.set(self.busy_polls.get() - 1);
+        }
+        self.push(Event::Busy(busy));
+        busy
+    }
+
+    /// Makes each panel update report busy for `polls` reads of the BUSY pin before ending.
+    pub fn set_update_polls(&self, polls: u32) {
+        self.update_polls.set(polls);
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    /// Number of BUSY reads that found the controller busy.
+    pub fn busy_polls(&self) -> usize {
+        self.events()
+            .iter()
+            .filter(|event| **event == Event::Busy(true))
+            .count()
+    }
+
+    /// Groups the bytes sent while the D/C pin is low (commands) with the bytes sent while it
+    /// is high (their data).
+    pub fn commands(&self) -> Vec<(u8, Vec<u8>)> {
+        let mut commands: Vec<(u8, Vec<u8>)> = Vec::new();
+        let mut data_mode = false;
+        for event in self.events() {
+            match event {
+                Event::Pin(Pin::DataCommand, level) => data_mode = level,
+                Event::Pin(Pin::Reset, _) | Event::Busy(_) => {}
+                Event::Spi(bytes) if data_mode => commands
+                    .last_mut()
+                    .expect("data sent before any command")
+                    .1
+                    .extend(bytes),
+                Event::Spi(bytes) => commands.extend(bytes.into_iter().map(|b| (b, Vec::new()))),
+            }
+        }
+        commands
+    }
+
+    /// Number of times the reset pin was pulled low.
+    pub fn resets(&self) -> usize {
+        self.events()
+            .iter()
+            .filter(|event| **event == Event::Pin(Pin::Reset, false))
+            .count()
+    }
+}
+
+pub struct MockSpi(Log);
+
+impl spi::ErrorType for MockSpi {
+    type Error = Infallible;
+}
+
+impl SpiDevice for MockSpi {
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+        for operation in operations {
+            match operation {
+                Operation::Read(buffer) => buffer.fill(0),
+                Operation::Write(buffer) => self.0.push(Event::Spi(buffer.to_vec())),
+                Operation::Transfer(read, write) => {
+                    self.0.push(Event::Spi(write.to_vec()));
+                    read.fill(0);
+                }
+                Operation::TransferInPlace(buffer) => {
+                    self.0.push(Event::Spi(buffer.to_vec()));
+                    buffer.fill(0);
+                }
+                Operation::DelayNs(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct MockOutputPin {
+    pin: Pin,
+    log: Log,
+}
+
+impl digital::ErrorType for MockOutputPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for MockOutputPin {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.log.push(Event::Pin(self.pin, false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.log.push(Event::Pin(self.pin, true));
+        Ok(())
+    }
+}
+
+/// BUSY pin of the mock controller, see [`Log::set_update_polls`].
+pub struct MockBusy(Log);
+
+impl digital::ErrorType for MockBusy {
+    type Error = Infallible;
+}
+
+impl InputPin for MockBusy {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.0.poll_busy())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.0.poll_busy())
+    }
+}
+
+pub struct MockDelay;
+
+impl DelayNs for MockDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+fn mocks(log: &Log) -> (MockOutputPin, MockOutputPin, MockBusy, MockDelay, MockSpi) {
+    (
+        MockOutputPin {
+            pin: Pin::Reset,
+            log: log.clone(),
+        },
+        MockOutputPin {
+            pin: Pin::DataCommand,
+            log: log.clone(),
+        },
+        MockBusy(log.clone()),
+        MockDelay,
+        MockSpi(log.clone()),
+    )
+}
+
+pub fn display() -> (TestDisplay, Log) {
+    let log = Log::default();
+    let (rst, dc, busy, delay, spi) = mocks(&log);
+    (
+        Ssd1680Display::new(rst, dc, busy, delay, spi, WIDTH, HEIGHT),
+        log,
+    )
+}
+
+pub fn tri_color_display() -> (TestTriColorDisplay, Log) {
+    let log = Log::default();
+    let (rst, dc, busy, delay, spi) = mocks(&log);
+    (
+        Ssd1680TriColorDisplay::new(rst, dc, busy, delay, spi, WIDTH, HEIGHT),
+        log,
+    )
+}
+
+pub fn grayscale_display() -> (TestGrayscaleDisplay, Log) {
+    let log = Log::default();
+    let (rst, dc, busy, delay, spi) = mocks(&log);
+    (
+        Ssd1680GrayscaleDisplay::new(rst, dc, busy, delay, spi, WIDTH, HEIGHT),
+        log,
+    )
+}
+
+#[cfg(feature = "async")]
+mod asynch {
+    use std::{convert::Infallible, task::Poll};
+
+    use embedded_hal::spi::Operation;
+    use embedded_hal_async::{delay::DelayNs, digital::Wait, spi::SpiDevice};
+
+    use super::{MockBusy, MockDelay, MockSpi};
+
+    impl SpiDevice for MockSpi {
+        async fn transaction(
+            &mut self,
+            operations: &mut [Operation<'_, u8>],
+        ) -> Result<(), Infallible> {
+            embedded_hal::spi::SpiDevice::transaction(self, operations)
+        }
+    }
+
+    impl Wait for MockBusy {
+        async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+            unreachable!("the displays only wait for the end of updates")
+        }
+
+        /// Pending for each poll finding the controller busy.
+        async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+            std::future::poll_fn(|_| {
+                if self.0.poll_busy() {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(Ok(()))
+                }
+            })
+            .await
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+            unreachable!("the displays only wait for the end of updates")
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+            unreachable!("the displays only wait for the end of updates")
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+            unreachable!("the displays only wait for the end of updates")
+        }
+    }
+
+    impl DelayNs for MockDelay {
+        async fn delay_ns(&mut self, _ns: u32) {}
+    }
+}
+
+#[cfg(feature = "async")]
+pub fn async_display() -> (TestAsyncDisplay, Log) {
+    let log = Log::default();
+    let (rst, dc, busy, delay, spi) = mocks(&log);
+    (
+        AsyncSsd1680Display::new(rst, dc, busy, delay, spi, WIDTH, HEIGHT),
+        log,
+    )
+}
+
+/// Runs a future of the mocks to completion, polling it again while the controller is busy.
+#[cfg(feature = "async")]
+pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
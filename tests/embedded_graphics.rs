@@ -0,0 +1,69 @@
+#![cfg(feature = "embedded-graphics")]
+
+use e_ink_graphics_library::{buffer_size, canvas::Canvas, embedded_graphics::BWDrawTarget};
+use embedded_graphics_core::{
+    Pixel,
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+};
+
+const WIDTH: u16 = 20;
+const HEIGHT: u16 = 10;
+const BUFFER_SIZE: usize = buffer_size(WIDTH, HEIGHT);
+
+/// Draws on a white canvas and returns its buffer.
+fn draw(f: impl FnOnce(&mut BWDrawTarget<Canvas<[u8; BUFFER_SIZE]>>)) -> [u8; BUFFER_SIZE] {
+    let mut canvas = Canvas::new([0xff; BUFFER_SIZE], WIDTH, HEIGHT);
+    f(&mut BWDrawTarget::new(&mut canvas));
+    canvas.into_buffer()
+}
+
+/// Colors of a pattern with runs of various lengths.
+fn color(i: usize) -> BinaryColor {
+    if i % 7 < 4 || i.is_multiple_of(11) {
+        BinaryColor::On
+    } else {
+        BinaryColor::Off
+    }
+}
+
+#[test]
+fn fill_solid_matches_drawn_pixels() {
+    // inside, crossing the bottom right corner and crossing the top left corner
+    let areas = [
+        Rectangle::new(Point::new(3, 2), Size::new(9, 4)),
+        Rectangle::new(Point::new(14, 6), Size::new(10, 10)),
+        Rectangle::new(Point::new(-3, -2), Size::new(6, 5)),
+    ];
+    for area in areas {
+        let solid = draw(|target| target.fill_solid(&area, BinaryColor::On).unwrap());
+        let pixels = draw(|target| {
+            let pixels = area.points().map(|point| Pixel(point, BinaryColor::On));
+            target.draw_iter(pixels).unwrap()
+        });
+        assert_eq!(solid, pixels, "{area:?}");
+        assert_ne!(solid, [0xff; BUFFER_SIZE], "{area:?}");
+    }
+}
+
+#[test]
+fn fill_contiguous_matches_drawn_pixels() {
+    let areas = [
+        Rectangle::new(Point::new(1, 1), Size::new(17, 3)),
+        Rectangle::new(Point::new(12, 7), Size::new(13, 6)),
+        Rectangle::new(Point::new(-5, -1), Size::new(9, 4)),
+    ];
+    for area in areas {
+        let contiguous = draw(|target| target.fill_contiguous(&area, (0..).map(color)).unwrap());
+        let pixels = draw(|target| {
+            let pixels = area
+                .points()
+                .enumerate()
+                .map(|(i, point)| Pixel(point, color(i)));
+            target.draw_iter(pixels).unwrap()
+        });
+        assert_eq!(contiguous, pixels, "{area:?}");
+    }
+}
@@ -0,0 +1,177 @@
+use e_ink_graphics_library::{
+    BWDraw, buffer_size,
+    canvas::Canvas,
+    font::{
+        FONT_6X8, Font, Glyph, HorizontalAlignment, KerningPair, TextBox, draw_text_box,
+        measure_text, measure_wrapped_text,
+    },
+};
+
+const WIDTH: u16 = 10;
+const HEIGHT: u16 = 8;
+
+/// Font of 4 pixels high lines where `'#'` is a 2x3 block and `'!'` a 1x3 bar, with a 3 pixels
+/// wide space.
+static BLOCKS: Font = Font {
+    first_char: ' ',
+    line_height: 4,
+    baseline: 3,
+    glyphs: &[
+        Glyph {
+            offset: 0,
+            width: 0,
+            height: 0,
+            x_offset: 0,
+            y_offset: 0,
+            advance: 3,
+        },
+        Glyph {
+            offset: 0,
+            width: 1,
+            height: 3,
+            x_offset: 0,
+            y_offset: 0,
+            advance: 2,
+        },
+        Glyph {
+            offset: 0,
+            width: 0,
+            height: 0,
+            x_offset: 0,
+            y_offset: 0,
+            advance: 3,
+        },
+        Glyph {
+            offset: 3,
+            width: 2,
+            height: 3,
+            x_offset: 0,
+            y_offset: 0,
+            advance: 3,
+        },
+    ],
+    bitmaps: &[0x80, 0x80, 0x80, 0xc0, 0xc0, 0xc0],
+    kerning: &[KerningPair {
+        left: '!',
+        right: '#',
+        adjust: 1,
+    }],
+};
+
+/// Draws in a text box of a white canvas and returns its rows, `'#'` for the black pixels.
+fn render(text_box: TextBox, text: &str, alignment: HorizontalAlignment) -> Vec<String> {
+    let mut canvas = Canvas::new([0xff; buffer_size(WIDTH, HEIGHT)], WIDTH, HEIGHT);
+    draw_text_box(&mut canvas, &text_box, text, &BLOCKS, false, alignment).unwrap();
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| {
+                    if canvas.get_pixel(x, y).unwrap() {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn measure_text_counts_every_line() {
+    assert_eq!(measure_text("", &FONT_6X8), (0, 8));
+    assert_eq!(measure_text("ab\nabcd", &FONT_6X8), (24, 16));
+    assert_eq!(measure_text("ab\n", &FONT_6X8), (12, 16));
+    // missing characters are measured as '?'
+    assert_eq!(measure_text("\u{e9}", &FONT_6X8), (6, 8));
+    assert_eq!(measure_text("!#", &BLOCKS), (6, 4));
+}
+
+#[test]
+fn wrapped_lines_break_at_spaces() {
+    assert_eq!(measure_wrapped_text("aa bb cc", &FONT_6X8, 30), (30, 16));
+    // the spaces at the break are dropped
+    assert_eq!(measure_wrapped_text("aa    bb", &FONT_6X8, 18), (12, 16));
+    assert_eq!(measure_wrapped_text("aa\nbb cc", &FONT_6X8, 100), (30, 16));
+    assert_eq!(measure_wrapped_text("", &FONT_6X8, 30), (0, 8));
+}
+
+#[test]
+fn long_words_are_broken_anywhere() {
+    assert_eq!(measure_wrapped_text("abcdefgh", &FONT_6X8, 30), (30, 16));
+    // a line holds at least one character
+    assert_eq!(measure_wrapped_text("ab", &FONT_6X8, 0), (6, 16));
+}
+
+#[test]
+fn text_box_wraps_the_lines() {
+    let text_box = TextBox {
+        x: 0,
+        y: 0,
+        width: 8,
+        height: 8,
+    };
+    assert_eq!(
+        render(text_box, "## ##", HorizontalAlignment::Left),
+        [
+            "##.##.....",
+            "##.##.....",
+            "##.##.....",
+            "..........",
+            "##.##.....",
+            "##.##.....",
+            "##.##.....",
+            "..........",
+        ]
+    );
+}
+
+#[test]
+fn text_box_drops_the_lines_below_it() {
+    let text_box = TextBox {
+        x: 0,
+        y: 0,
+        width: 8,
+        height: 7,
+    };
+    assert_eq!(
+        render(text_box, "## ##", HorizontalAlignment::Right),
+        [
+            "..##.##...",
+            "..##.##...",
+            "..##.##...",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+        ]
+    );
+}
+
+#[test]
+fn text_box_is_clipped_at_the_display_edges() {
+    let text_box = TextBox {
+        x: 6,
+        y: 2,
+        width: 6,
+        height: 4,
+    };
+    assert_eq!(
+        render(text_box, "####", HorizontalAlignment::Center),
+        [
+            "..........",
+            "..........",
+            "......##.#",
+            "......##.#",
+            "......##.#",
+            "..........",
+            "..........",
+            "..........",
+        ]
+    );
+    assert_eq!(
+        render(text_box, "", HorizontalAlignment::Center),
+        [".........."; HEIGHT as usize]
+    );
+}
@@ -0,0 +1,233 @@
+#![cfg(feature = "std")]
+
+use std::{fs, process::Command};
+
+/// Runs `font-converter` on `data` saved as `file_name`, returns its output or its error.
+fn convert(file_name: &str, data: &[u8], args: &[&str]) -> Result<String, String> {
+    let directory = std::env::temp_dir().join(format!("font-converter-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let path = directory.join(file_name);
+    fs::write(&path, data).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_font-converter"))
+        .arg(&path)
+        .arg("TEST")
+        .args(args)
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    if output.status.success() {
+        Ok(String::from_utf8(output.stdout).unwrap())
+    } else {
+        Err(String::from_utf8(output.stderr).unwrap())
+    }
+}
+
+fn be16(bytes: &mut Vec<u8>, values: &[u16]) {
+    for value in values {
+        bytes.extend(value.to_be_bytes());
+    }
+}
+
+/// 4x4 square from (1, 0) to (5, 4), its points going clockwise from the bottom left.
+fn square() -> Vec<u8> {
+    let mut glyph = Vec::new();
+    // 1 contour, bounding box, end of the contour, no instructions
+    be16(&mut glyph, &[1, 1, 0, 5, 4, 3, 0]);
+    // on curve points with 16 bits coordinates, then the x and y deltas
+    glyph.extend([0x01; 4]);
+    be16(&mut glyph, &[1, 0, 4, 0]);
+    be16(&mut glyph, &[0, 4, 0, (-4i16) as u16]);
+    glyph
+}
+
+/// Font of 8 units per em with 'A' a square, 'B' the square moved right by an offset and 'C'
+/// two squares side by side, the second one placed by point matching. 'A' 'B' are kerned by -1.
+fn truetype(loca_format: u16) -> Vec<u8> {
+    let mut glyf = square();
+    let b = glyf.len();
+    // composite glyph: 16 bits x and y offsets
+    be16(&mut glyf, &[(-1i16) as u16, 2, 0, 7, 4]);
+    be16(&mut glyf, &[0x0003, 1, 2, 0]);
+    let c = glyf.len();
+    // the first square at the origin, then point 0 of the second one on point 3 of the first
+    be16(&mut glyf, &[(-1i16) as u16, 1, 0, 9, 4]);
+    be16(&mut glyf, &[0x0023, 1, 0, 0]);
+    be16(&mut glyf, &[0x0000, 1]);
+    glyf.extend([3, 0]);
+    let end = glyf.len();
+    let mut loca = Vec::new();
+    be16(
+        &mut loca,
+        &[0, 0, b as u16 / 2, c as u16 / 2, end as u16 / 2],
+    );
+
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&8u16.to_be_bytes());
+    head[50..52].copy_from_slice(&loca_format.to_be_bytes());
+    let mut hhea = vec![0; 36];
+    // ascent, descent and line gap, then the number of advances
+    hhea[4..10].copy_from_slice(&[0, 6, 0xff, 0xfe, 0, 0]);
+    hhea[34..36].copy_from_slice(&4u16.to_be_bytes());
+    let mut hmtx = Vec::new();
+    be16(&mut hmtx, &[0, 0, 6, 1, 8, 1, 10, 1]);
+
+    let mut cmap = Vec::new();
+    // a Windows Unicode BMP format 4 subtable, 'A' to 'C' then the final segment
+    be16(&mut cmap, &[0, 1, 3, 1, 0, 12]);
+    be16(&mut cmap, &[4, 32, 0, 4, 4, 1, 0]);
+    be16(&mut cmap, &[0x43, 0xffff, 0, 0x41, 0xffff]);
+    be16(&mut cmap, &[1u16.wrapping_sub(0x41), 1, 0, 0]);
+
+    let mut kern = Vec::new();
+    // version 0, 1 subtable of horizontal format 0 pairs
+    be16(&mut kern, &[0, 1, 0, 20, 0x0001, 1, 6, 0, 0]);
+    be16(&mut kern, &[1, 2, (-1i16) as u16]);
+
+    let tables: [(&[u8; 4], Vec<u8>); 7] = [
+        (b"cmap", cmap),
+        (b"glyf", glyf),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"kern", kern),
+        (b"loca", loca),
+    ];
+    let mut font = Vec::new();
+    be16(&mut font, &[1, 0, tables.len() as u16, 0, 0, 0]);
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, table) in &tables {
+        font.extend(*tag);
+        font.extend([0; 4]);
+        font.extend((offset as u32).to_be_bytes());
+        font.extend((table.len() as u32).to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+    for (_, table) in tables {
+        font.extend(table);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    font
+}
+
+#[test]
+fn truetype_outlines_composites_and_kerning_are_converted() {
+    let source = convert(
+        "test.ttf",
+        &truetype(0),
+        &["--size", "8", "--first", "A", "--last", "C"],
+    )
+    .unwrap();
+
+    let expected = "\
+//! Font generated by `font-converter` from test.ttf at 8 pixels.
+
+use e_ink_graphics_library::font::{Font, Glyph, KerningPair};
+
+pub const TEST: Font = Font {
+    first_char: 'A',
+    line_height: 8,
+    baseline: 6,
+    glyphs: &GLYPHS,
+    bitmaps: &BITMAPS,
+    kerning: &KERNING,
+};
+
+#[rustfmt::skip]
+const GLYPHS: [Glyph; 3] = [
+    Glyph { offset: 0, width: 4, height: 4, x_offset: 1, y_offset: 2, advance: 6 }, // 'A'
+    Glyph { offset: 4, width: 4, height: 4, x_offset: 3, y_offset: 2, advance: 8 }, // 'B'
+    Glyph { offset: 8, width: 8, height: 4, x_offset: 1, y_offset: 2, advance: 10 }, // 'C'
+];
+
+#[rustfmt::skip]
+const KERNING: [KerningPair; 1] = [
+    KerningPair { left: 'A', right: 'B', adjust: -1 },
+];
+
+#[rustfmt::skip]
+const BITMAPS: [u8; 12] = [
+    0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xff, 0xff, 0xff, 0xff,
+];
+";
+    assert_eq!(source, expected);
+}
+
+#[test]
+fn corrupted_truetype_tables_are_rejected() {
+    let args = ["--size", "8", "--first", "A", "--last", "C"];
+    let error = convert("bad_loca.ttf", &truetype(2), &args).unwrap_err();
+    assert_eq!(
+        error,
+        "font-converter: corrupted head table: loca format 2\n"
+    );
+
+    let mut font = truetype(0);
+    font.truncate(font.len() - 40);
+    let error = convert("truncated.ttf", &font, &args).unwrap_err();
+    assert_eq!(error, "font-converter: truncated or corrupted font\n");
+}
+
+#[test]
+fn bdf_bitmaps_are_converted_as_they_are() {
+    let bdf = "\
+STARTFONT 2.1
+FONTBOUNDINGBOX 6 8 0 -2
+FONT_ASCENT 6
+FONT_DESCENT 2
+CHARS 2
+STARTCHAR a
+ENCODING 97
+DWIDTH 5 0
+BBX 3 3 1 0
+BITMAP
+E0
+A0
+E0
+ENDCHAR
+STARTCHAR b
+ENCODING 98
+DWIDTH 6 0
+BBX 2 5 0 -1
+BITMAP
+80
+80
+C0
+40
+C0
+ENDCHAR
+ENDFONT
+";
+    let source = convert("test.bdf", bdf.as_bytes(), &["--first", "a", "--last", "c"]).unwrap();
+
+    let expected = "\
+//! Font generated by `font-converter` from test.bdf.
+
+use e_ink_graphics_library::font::{Font, Glyph, KerningPair};
+
+pub const TEST: Font = Font {
+    first_char: 'a',
+    line_height: 8,
+    baseline: 6,
+    glyphs: &GLYPHS,
+    bitmaps: &BITMAPS,
+    kerning: &KERNING,
+};
+
+#[rustfmt::skip]
+const GLYPHS: [Glyph; 3] = [
+    Glyph { offset: 0, width: 3, height: 3, x_offset: 1, y_offset: 3, advance: 5 }, // 'a'
+    Glyph { offset: 3, width: 2, height: 5, x_offset: 0, y_offset: 2, advance: 6 }, // 'b'
+    Glyph { offset: 8, width: 0, height: 0, x_offset: 0, y_offset: 0, advance: 0 }, // 'c'
+];
+
+#[rustfmt::skip]
+const KERNING: [KerningPair; 0] = [
+];
+
+#[rustfmt::skip]
+const BITMAPS: [u8; 8] = [
+    0xe0, 0xa0, 0xe0, 0x80, 0x80, 0xc0, 0x40, 0xc0,
+];
+";
+    assert_eq!(source, expected);
+}
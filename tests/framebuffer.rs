@@ -0,0 +1,91 @@
+use std::convert::Infallible;
+
+use e_ink_graphics_library::{
+    BWDisplay, BWDraw, buffer_size,
+    framebuffer::{Area, FrameBuffer, PanelDriver, RamPlane, RefreshMode},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Call {
+    Init,
+    SetWindow(Area),
+    WritePlane(RamPlane, Vec<u8>),
+    Refresh(RefreshMode),
+    Sleep,
+}
+
+/// Panel recording the calls of the frame buffer.
+#[derive(Default)]
+struct MockPanel(Vec<Call>);
+
+impl PanelDriver for MockPanel {
+    type Error = Infallible;
+
+    fn init(&mut self) -> Result<(), Infallible> {
+        self.0.push(Call::Init);
+        Ok(())
+    }
+
+    fn set_window(&mut self, area: Area) -> Result<(), Infallible> {
+        self.0.push(Call::SetWindow(area));
+        Ok(())
+    }
+
+    fn write_plane(&mut self, plane: RamPlane, data: &[u8]) -> Result<(), Infallible> {
+        self.0.push(Call::WritePlane(plane, data.to_vec()));
+        Ok(())
+    }
+
+    fn refresh(&mut self, mode: RefreshMode) -> Result<(), Infallible> {
+        self.0.push(Call::Refresh(mode));
+        Ok(())
+    }
+
+    fn is_busy(&mut self) -> Result<bool, Infallible> {
+        Ok(false)
+    }
+
+    fn sleep(&mut self) -> Result<(), Infallible> {
+        self.0.push(Call::Sleep);
+        Ok(())
+    }
+}
+
+#[test]
+fn partial_refresh_writes_the_changed_rows_to_the_panel() {
+    let mut display =
+        FrameBuffer::<_, { buffer_size(16, 4) }>::from_panel(MockPanel::default(), 16, 4);
+    display.set_auto_sleep(false);
+    display.fill(true).unwrap();
+    display.refresh(true).unwrap();
+    display.panel_mut().0.clear();
+
+    display.set_pixel(9, 2, false).unwrap();
+    display.refresh(false).unwrap();
+
+    let window = Area {
+        x: 9,
+        y: 2,
+        w: 1,
+        h: 1,
+    };
+    let full = Area {
+        x: 0,
+        y: 0,
+        w: 16,
+        h: 4,
+    };
+    assert_eq!(
+        display.panel().0,
+        [
+            Call::SetWindow(window),
+            Call::WritePlane(RamPlane::Frame, vec![0xbf]),
+            Call::SetWindow(full),
+            Call::Refresh(RefreshMode::Partial),
+            // the frame shown becomes the reference of the next partial refresh
+            Call::SetWindow(window),
+            Call::WritePlane(RamPlane::Reference, vec![0xbf]),
+            Call::SetWindow(full),
+        ]
+    );
+}
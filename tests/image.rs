@@ -0,0 +1,121 @@
+use e_ink_graphics_library::{
+    BWDraw, buffer_size,
+    canvas::Canvas,
+    image::{Ditherer, Dithering, PixelFormat, draw_image, scratch_len},
+    pattern::Pattern,
+};
+
+const WIDTH: u16 = 6;
+const HEIGHT: u16 = 4;
+
+/// Dithers the `width` pixels wide gray image at (`x`, 0) of a white canvas and returns its
+/// rows, `'#'` for the black pixels.
+fn render(x: u16, width: u16, image: &[u8], dithering: Dithering) -> Vec<String> {
+    let mut canvas = Canvas::new([0xff; buffer_size(WIDTH, HEIGHT)], WIDTH, HEIGHT);
+    let mut scratch = [0; scratch_len(WIDTH, Dithering::Atkinson)];
+    draw_image(
+        &mut canvas,
+        x,
+        0,
+        width,
+        PixelFormat::Gray8,
+        image,
+        dithering,
+        &mut scratch,
+    )
+    .unwrap();
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| {
+                    if canvas.get_pixel(x, y).unwrap() {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn threshold_keeps_the_pixels_at_least_as_bright_as_the_level() {
+    let image = [0, 127, 128, 129, 254, 255];
+    assert_eq!(render(0, 6, &image, Dithering::Threshold(128))[0], "##....");
+    assert_eq!(render(0, 6, &image, Dithering::Threshold(0))[0], "......");
+    assert_eq!(render(0, 6, &image, Dithering::Threshold(255))[0], "#####.");
+}
+
+#[test]
+fn rgb_pixels_are_thresholded_on_their_luminance() {
+    let mut canvas = Canvas::new([0xff; buffer_size(WIDTH, HEIGHT)], WIDTH, HEIGHT);
+    // pure red, green and blue have a luminance of 76, 149 and 28
+    let image = [255, 0, 0, 0, 255, 0, 0, 0, 255];
+    for (level, expected) in [(29, [true, true, false]), (77, [false, true, false])] {
+        draw_image(
+            &mut canvas,
+            0,
+            0,
+            3,
+            PixelFormat::Rgb888,
+            &image,
+            Dithering::Threshold(level),
+            &mut [],
+        )
+        .unwrap();
+        let colors = [0, 1, 2].map(|x| canvas.get_pixel(x, 0).unwrap());
+        assert_eq!(colors, expected, "threshold {level}");
+    }
+}
+
+#[test]
+fn floyd_steinberg_output_is_stable() {
+    assert_eq!(
+        render(0, 6, &[96; 24], Dithering::FloydSteinberg),
+        ["#.##.#", "##.##.", ".#.#.#", "##.##."]
+    );
+    assert_eq!(
+        render(
+            0,
+            6,
+            &[0, 64, 128, 192, 255, 128].repeat(3),
+            Dithering::FloydSteinberg
+        ),
+        ["##...#", "###...", "##...#", "......"]
+    );
+}
+
+#[test]
+fn atkinson_output_is_stable() {
+    assert_eq!(
+        render(0, 6, &[96; 24], Dithering::Atkinson),
+        ["######", "#..#.#", "####.#", ".##.##"]
+    );
+}
+
+#[test]
+fn clipped_pixels_still_spread_their_error() {
+    // the visible part is the left half of the unclipped image, and the rows below the display
+    // are dropped
+    let clipped = render(3, 6, &[96; 30], Dithering::FloydSteinberg);
+    assert_eq!(clipped, ["...#.#", "...##.", "....#.", "...##."]);
+}
+
+#[test]
+fn bayer_dithering_is_aligned_to_the_display_like_the_patterns() {
+    const SIZE: u16 = 16;
+    let mut canvas = Canvas::new([0xff; buffer_size(SIZE, SIZE)], SIZE, SIZE);
+    let mut ditherer = Ditherer::new(3, 5, 10, PixelFormat::Gray8, Dithering::Bayer, &mut []);
+    for _ in 0..10 {
+        ditherer.draw_row(&mut canvas, &[128; 10]).unwrap();
+    }
+    let pattern = Pattern::gray(50);
+    for y in 5..15 {
+        for x in 3..13 {
+            assert_eq!(canvas.get_pixel(x, y).unwrap(), pattern.color_at(x, y));
+        }
+    }
+    assert!(canvas.get_pixel(2, 5).unwrap());
+    assert!(canvas.get_pixel(13, 5).unwrap());
+}
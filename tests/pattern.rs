@@ -0,0 +1,113 @@
+use e_ink_graphics_library::{
+    BWDraw, DisplayError, buffer_size,
+    canvas::Canvas,
+    pattern::Pattern,
+    primitives::{fill_polygon, fill_rect},
+};
+
+const WIDTH: u16 = 80;
+const HEIGHT: u16 = 12;
+
+fn white_canvas() -> Canvas<[u8; buffer_size(WIDTH, HEIGHT)]> {
+    Canvas::new([0xff; buffer_size(WIDTH, HEIGHT)], WIDTH, HEIGHT)
+}
+
+/// Asserts that every pixel of the `w` x `h` rectangle at (`x`, `y`) is the pattern color.
+fn assert_tiled(canvas: &impl BWDraw, pattern: Pattern, x: u16, y: u16, w: u16, h: u16) {
+    for y in y..y + h {
+        for x in x..x + w {
+            assert_eq!(
+                canvas.get_pixel(x, y).ok(),
+                Some(pattern.color_at(x, y)),
+                "pixel ({x}, {y})"
+            );
+        }
+    }
+}
+
+#[test]
+fn unaligned_rectangles_join_seamlessly() {
+    let pattern = Pattern::FORWARD_DIAGONAL;
+    let mut canvas = white_canvas();
+    // a grid of rectangles starting and ending inside the bytes of the rows
+    let columns = [0, 3, 11, 13, 30, 37, 80];
+    let rows = [0, 5, 7, 12];
+    for (top, bottom) in rows.iter().zip(&rows[1..]) {
+        for (left, right) in columns.iter().zip(&columns[1..]) {
+            fill_rect(
+                &mut canvas,
+                *left,
+                *top,
+                (right - left) as u16,
+                (bottom - top) as u16,
+                pattern,
+            )
+            .unwrap();
+        }
+    }
+    assert_tiled(&canvas, pattern, 0, 0, WIDTH, HEIGHT);
+}
+
+#[test]
+fn tiles_are_aligned_to_the_display_and_not_to_the_shape() {
+    let mut canvas = white_canvas();
+    fill_rect(&mut canvas, 3, 1, 10, 2, Pattern::FORWARD_DIAGONAL).unwrap();
+    // rows 0xdd and 0xbb of the tile, from x = 3 to 12
+    assert_eq!(canvas.buffer()[10..12], [0xfd, 0xdf]);
+    assert_eq!(canvas.buffer()[20..22], [0xfb, 0xbf]);
+}
+
+#[test]
+fn rows_longer_than_a_chunk_stay_aligned() {
+    let pattern = Pattern::gray(30);
+    let mut canvas = white_canvas();
+    fill_rect(&mut canvas, 5, 2, 70, 3, pattern).unwrap();
+    assert_tiled(&canvas, pattern, 5, 2, 70, 3);
+    assert_tiled(&canvas, Pattern::WHITE, 0, 2, 5, 3);
+    assert_tiled(&canvas, Pattern::WHITE, 75, 2, 5, 3);
+}
+
+#[test]
+fn shapes_clipped_at_the_edges_keep_the_tiling() {
+    let pattern = Pattern::CHECKERBOARD.inverted();
+    let mut canvas = white_canvas();
+    fill_rect(&mut canvas, -3, -5, 10, 9, pattern).unwrap();
+    fill_rect(&mut canvas, 77, 9, 7, 7, pattern).unwrap();
+    fill_polygon(&mut canvas, &[(-9, 6), (20, 6), (20, 9), (-9, 9)], pattern).unwrap();
+    assert_tiled(&canvas, pattern, 0, 0, 7, 4);
+    assert_tiled(&canvas, pattern, 0, 6, 20, 3);
+    assert_tiled(&canvas, pattern, 77, 9, 3, 3);
+    assert_tiled(&canvas, Pattern::WHITE, 7, 0, 70, 4);
+}
+
+#[test]
+fn gray_levels_blacken_the_expected_share_of_the_tile() {
+    let black_pixels = |pattern: Pattern| {
+        pattern
+            .rows()
+            .iter()
+            .map(|row| row.count_zeros())
+            .sum::<u32>()
+    };
+    assert_eq!(Pattern::gray(0), Pattern::WHITE);
+    assert_eq!(Pattern::gray(100), Pattern::BLACK);
+    assert_eq!(Pattern::gray(200), Pattern::BLACK);
+    assert_eq!(black_pixels(Pattern::gray(25)), 16);
+    assert_eq!(black_pixels(Pattern::gray(50)), 32);
+    assert_eq!(Pattern::gray(50), Pattern::CHECKERBOARD);
+    assert_eq!(Pattern::gray(1).rows()[0], 0x7f);
+}
+
+#[test]
+fn pattern_fills_off_the_display_fail_like_solid_ones() {
+    // nothing of a 0 pixels wide canvas is on the display
+    let mut canvas = Canvas::new([0; 0], 0, HEIGHT);
+    assert!(matches!(
+        canvas.fill_rect(0, 0, WIDTH, HEIGHT, false),
+        Err(DisplayError::OutOfBounds)
+    ));
+    assert!(matches!(
+        canvas.fill_pattern(Pattern::CHECKERBOARD),
+        Err(DisplayError::OutOfBounds)
+    ));
+}
@@ -0,0 +1,222 @@
+use e_ink_graphics_library::{
+    BWDraw, DisplayError, Mirror, Rotation, TransparencySetting, buffer_size, canvas::Canvas,
+};
+
+/// Canvas whose bytes are all 0b0011_1100, so that both colors are overwritten or kept.
+fn striped_canvas(width: u16, height: u16) -> Canvas<Vec<u8>> {
+    Canvas::new(vec![0x3c; buffer_size(width, height)], width, height)
+}
+
+#[test]
+fn aligned_blit_blends_whole_bytes() {
+    for (transparency, expected) in [
+        (TransparencySetting::None, 0xca),
+        // only the white pixels of the source are drawn
+        (TransparencySetting::BlackTransparent, 0xfe),
+        // only the black pixels of the source are drawn
+        (TransparencySetting::WhiteTransparent, 0x08),
+    ] {
+        let mut canvas = striped_canvas(24, 2);
+        canvas
+            .draw_buffer_with_transparency(&[0xca], 8, 0, 8, 1, transparency)
+            .unwrap();
+        assert_eq!(
+            canvas.buffer(),
+            [0x3c, expected, 0x3c, 0x3c, 0x3c, 0x3c],
+            "{transparency:?}"
+        );
+    }
+}
+
+#[test]
+fn unaligned_blit_carries_into_the_next_byte() {
+    // the last 3 pixels of the source byte land at the start of the next byte
+    for (transparency, expected) in [
+        (TransparencySetting::None, [0x39, 0x5c]),
+        (TransparencySetting::BlackTransparent, [0x3d, 0x7c]),
+        (TransparencySetting::WhiteTransparent, [0x38, 0x1c]),
+    ] {
+        let mut canvas = striped_canvas(24, 2);
+        canvas
+            .draw_buffer_with_transparency(&[0xca], 11, 0, 8, 1, transparency)
+            .unwrap();
+        assert_eq!(
+            canvas.buffer(),
+            [0x3c, expected[0], expected[1], 0x3c, 0x3c, 0x3c],
+            "{transparency:?}"
+        );
+    }
+}
+
+#[test]
+fn blit_is_clipped_at_the_right_edge() {
+    // 6 of the 8 pixels of each row are on the 20 pixels wide canvas, the padding bits at the
+    // end of the rows and the start of the next row are left untouched
+    for (transparency, expected) in [
+        (
+            TransparencySetting::None,
+            [0x3c, 0x3f, 0x2c, 0x3c, 0x3c, 0xdc],
+        ),
+        (
+            TransparencySetting::BlackTransparent,
+            [0x3c, 0x3f, 0x3c, 0x3c, 0x3c, 0xfc],
+        ),
+        (
+            TransparencySetting::WhiteTransparent,
+            [0x3c, 0x3c, 0x2c, 0x3c, 0x3c, 0x1c],
+        ),
+    ] {
+        let mut canvas = striped_canvas(20, 2);
+        canvas
+            .draw_buffer_with_transparency(&[0xca, 0x35], 14, 0, 8, 2, transparency)
+            .unwrap();
+        assert_eq!(canvas.buffer(), expected, "{transparency:?}");
+    }
+}
+
+/// Canvas of 16x8 white pixels.
+fn white_canvas() -> Canvas<Vec<u8>> {
+    Canvas::new(vec![0xff; buffer_size(16, 8)], 16, 8)
+}
+
+#[test]
+fn drawing_starting_off_the_canvas_is_out_of_bounds() {
+    let mut canvas = white_canvas();
+    for (x, y) in [(16, 0), (0, 8), (u16::MAX, u16::MAX)] {
+        assert!(matches!(
+            canvas.fill_rect(x, y, 1, 1, false),
+            Err(DisplayError::OutOfBounds)
+        ));
+        assert!(matches!(
+            canvas.draw_buffer(&[0x00], x, y, 8, 1),
+            Err(DisplayError::OutOfBounds)
+        ));
+    }
+    assert_eq!(canvas.buffer(), [0xff; 16]);
+}
+
+#[test]
+fn short_buffers_are_rejected() {
+    let mut canvas = white_canvas();
+    // 9 pixels wide rows take 2 bytes
+    assert!(matches!(
+        canvas.draw_buffer(&[0x00; 3], 0, 0, 9, 2),
+        Err(DisplayError::InvalidBufferLength)
+    ));
+    assert!(matches!(
+        canvas.set_buffer(&[0x00; 15]),
+        Err(DisplayError::InvalidBufferLength)
+    ));
+    assert_eq!(canvas.buffer(), [0xff; 16]);
+}
+
+#[test]
+fn drawing_is_clipped_at_the_right_edge() {
+    let mut canvas = white_canvas();
+    canvas.fill_rect(13, 0, u16::MAX, 1, false).unwrap();
+    canvas.draw_buffer(&[0x00, 0x00], 12, 1, 16, 1).unwrap();
+    assert_eq!(canvas.buffer()[..6], [0xff, 0xf8, 0xff, 0xf0, 0xff, 0xff]);
+}
+
+#[test]
+fn drawing_is_clipped_at_the_bottom_edge() {
+    let mut canvas = white_canvas();
+    canvas.fill_rect(0, 7, 1, u16::MAX, false).unwrap();
+    // only the first row of the 3 rows buffer is drawn
+    canvas.draw_buffer(&[0x0f, 0x00, 0x00], 8, 7, 8, 3).unwrap();
+    assert_eq!(canvas.buffer()[12..], [0xff, 0xff, 0x7f, 0x0f]);
+}
+
+/// Draws on a 8x4 white canvas with the given orientation and returns the rows of its buffer,
+/// `'#'` for the black pixels.
+///
+/// The pixel at (0, 0) is set, (2, 0) to (3, 0) are filled and a 2x2 sprite puts (0, 2),
+/// (0, 3) and (1, 3) in black.
+fn draw_oriented(rotation: Rotation, mirror: Mirror) -> Vec<String> {
+    let mut canvas = Canvas::new([0xff; 4], 8, 4);
+    canvas.set_rotation(rotation);
+    canvas.set_mirror(mirror);
+    canvas.set_pixel(0, 0, false).unwrap();
+    canvas.fill_rect(2, 0, 2, 1, false).unwrap();
+    canvas
+        .draw_buffer(&[0b0111_1111, 0b0011_1111], 0, 2, 2, 2)
+        .unwrap();
+    canvas
+        .buffer()
+        .iter()
+        .map(|row| {
+            (0..8)
+                .map(|x| if row & (0x80 >> x) != 0 { '.' } else { '#' })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn rotations_turn_the_drawing_clockwise() {
+    assert_eq!(
+        draw_oriented(Rotation::Rotate0, Mirror::None),
+        ["#.##....", "........", "#.......", "##......"]
+    );
+    assert_eq!(
+        draw_oriented(Rotation::Rotate90, Mirror::None),
+        ["....##.#", "....#...", ".......#", ".......#"]
+    );
+    assert_eq!(
+        draw_oriented(Rotation::Rotate180, Mirror::None),
+        ["......##", ".......#", "........", "....##.#"]
+    );
+    assert_eq!(
+        draw_oriented(Rotation::Rotate270, Mirror::None),
+        ["#.......", "#.......", "...#....", "#.##...."]
+    );
+}
+
+#[test]
+fn mirrors_flip_the_drawing() {
+    assert_eq!(
+        draw_oriented(Rotation::Rotate0, Mirror::Horizontal),
+        ["....##.#", "........", ".......#", "......##"]
+    );
+    assert_eq!(
+        draw_oriented(Rotation::Rotate0, Mirror::Vertical),
+        ["##......", "#.......", "........", "#.##...."]
+    );
+    assert_eq!(
+        draw_oriented(Rotation::Rotate0, Mirror::Both),
+        draw_oriented(Rotation::Rotate180, Mirror::None)
+    );
+    // the mirror applies to the rotated coordinates
+    assert_eq!(
+        draw_oriented(Rotation::Rotate90, Mirror::Horizontal),
+        [".......#", ".......#", "....#...", "....##.#"]
+    );
+}
+
+#[test]
+fn quarter_rotations_swap_width_and_height() {
+    let mut canvas = Canvas::new([0xff; 4], 8, 4);
+    for (rotation, width, height) in [
+        (Rotation::Rotate0, 8, 4),
+        (Rotation::Rotate90, 4, 8),
+        (Rotation::Rotate180, 8, 4),
+        (Rotation::Rotate270, 4, 8),
+    ] {
+        canvas.set_rotation(rotation);
+        assert_eq!((canvas.width(), canvas.height()), (width, height));
+        assert!(canvas.set_pixel(width - 1, height - 1, true).is_ok());
+        assert!(matches!(
+            canvas.set_pixel(width, 0, true),
+            Err(DisplayError::OutOfBounds)
+        ));
+        assert!(matches!(
+            canvas.fill_rect(0, height, 1, 1, true),
+            Err(DisplayError::OutOfBounds)
+        ));
+    }
+    // rectangles and buffers are clipped to the rotated height
+    canvas.set_rotation(Rotation::Rotate90);
+    canvas.fill_rect(3, 6, 1, u16::MAX, false).unwrap();
+    canvas.draw_buffer(&[0x00; 4], 2, 6, 1, 4).unwrap();
+    assert_eq!(canvas.buffer(), [0xff, 0xff, 0x3f, 0x3f]);
+}
@@ -0,0 +1,220 @@
+use e_ink_graphics_library::{BWDraw, buffer_size, canvas::Canvas, primitives::*};
+
+const WIDTH: u16 = 10;
+const HEIGHT: u16 = 8;
+
+type TestCanvas = Canvas<[u8; buffer_size(WIDTH, HEIGHT)]>;
+
+/// Draws in black on a white canvas and returns its rows, `'#'` for the black pixels.
+fn render(draw: impl FnOnce(&mut TestCanvas)) -> Vec<String> {
+    let mut canvas = Canvas::new([0xff; buffer_size(WIDTH, HEIGHT)], WIDTH, HEIGHT);
+    draw(&mut canvas);
+    (0..HEIGHT)
+        .map(|y| {
+            (0..WIDTH)
+                .map(|x| {
+                    if canvas.get_pixel(x, y).unwrap() {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn lines_include_both_ends() {
+    assert_eq!(
+        render(|canvas| {
+            draw_line(canvas, 8, 6, 8, 6, false).unwrap();
+            draw_line(canvas, 0, 0, 4, 2, false).unwrap();
+            draw_line(canvas, 9, 0, 9, 3, false).unwrap();
+        }),
+        [
+            "#........#",
+            ".##......#",
+            "...##....#",
+            ".........#",
+            "..........",
+            "..........",
+            "........#.",
+            "..........",
+        ]
+    );
+}
+
+#[test]
+fn lines_are_clipped_at_the_edges() {
+    assert_eq!(
+        render(|canvas| {
+            draw_line(canvas, -5, 1, 20, 1, false).unwrap();
+            draw_line(canvas, -2, 1, 3, 6, false).unwrap();
+            draw_line(canvas, 7, 5, 12, 10, false).unwrap();
+            draw_line(canvas, -3, -3, -1, -1, false).unwrap();
+        }),
+        [
+            "..........",
+            "##########",
+            "..........",
+            "#.........",
+            ".#........",
+            "..#....#..",
+            "...#....#.",
+            ".........#",
+        ]
+    );
+}
+
+#[test]
+fn thick_lines_cover_their_width() {
+    assert_eq!(
+        render(|canvas| {
+            draw_thick_line(canvas, 2, 2, 7, 2, 3, false).unwrap();
+            draw_thick_line(canvas, 0, 0, 0, 0, 3, false).unwrap();
+            draw_thick_line(canvas, 8, 7, 8, 7, 3, false).unwrap();
+            draw_thick_line(canvas, 3, 6, 5, 6, 1, false).unwrap();
+        }),
+        [
+            "##........",
+            "########..",
+            "..######..",
+            "..######..",
+            "..........",
+            "..........",
+            "...###.###",
+            ".......###",
+        ]
+    );
+}
+
+#[test]
+fn rectangles_are_clipped_and_empty_ones_skipped() {
+    assert_eq!(
+        render(|canvas| {
+            draw_rect(canvas, 1, 1, 4, 3, false).unwrap();
+            draw_rect(canvas, 7, -1, 5, 4, false).unwrap();
+            fill_rect(canvas, -2, 5, 4, 9, false).unwrap();
+            draw_rect(canvas, 5, 5, 0, 2, false).unwrap();
+            fill_rect(canvas, 5, 5, 2, 0, false).unwrap();
+        }),
+        [
+            ".......#..",
+            ".####..#..",
+            ".#..#..###",
+            ".####.....",
+            "..........",
+            "##........",
+            "##........",
+            "##........",
+        ]
+    );
+}
+
+#[test]
+fn rounded_rectangle_radii_are_clamped_to_the_sides() {
+    assert_eq!(
+        render(|canvas| {
+            draw_rounded_rect(canvas, 0, 0, 4, 3, 0, false).unwrap();
+            fill_rounded_rect(canvas, 5, 0, 4, 3, 0, false).unwrap();
+            draw_rounded_rect(canvas, 0, 3, 7, 5, 2, false).unwrap();
+            fill_rounded_rect(canvas, 7, 4, 6, 4, 9, false).unwrap();
+        }),
+        [
+            "####.####.",
+            "#..#.####.",
+            "####.####.",
+            ".#####....",
+            "#.....#.##",
+            "#.....####",
+            "#.....####",
+            ".#####..##",
+        ]
+    );
+}
+
+#[test]
+fn circles_of_no_radius_are_a_pixel() {
+    assert_eq!(
+        render(|canvas| {
+            draw_circle(canvas, 1, 1, 0, false).unwrap();
+            fill_circle(canvas, 1, 4, 0, false).unwrap();
+            draw_circle(canvas, 5, 3, 2, false).unwrap();
+            fill_circle(canvas, 9, 7, 2, false).unwrap();
+        }),
+        [
+            "..........",
+            ".#..###...",
+            "...#...#..",
+            "...#...#..",
+            ".#.#...#..",
+            "....###.##",
+            ".......###",
+            ".......###",
+        ]
+    );
+}
+
+#[test]
+fn ellipses_are_clipped_at_the_edges() {
+    assert_eq!(
+        render(|canvas| {
+            draw_ellipse(canvas, 4, 2, 4, 2, false).unwrap();
+            fill_ellipse(canvas, 0, 7, 3, 1, false).unwrap();
+            fill_ellipse(canvas, 8, 6, 0, 1, false).unwrap();
+        }),
+        [
+            "..#####...",
+            "##.....##.",
+            "#.......#.",
+            "##.....##.",
+            "..#####...",
+            "........#.",
+            "###.....#.",
+            "####....#.",
+        ]
+    );
+}
+
+#[test]
+fn arcs_go_clockwise_from_the_x_axis() {
+    assert_eq!(
+        render(|canvas| {
+            draw_arc(canvas, 3, 3, 3, 0, 90, false).unwrap();
+            draw_arc(canvas, 7, 3, 2, 0, -90, false).unwrap();
+        }),
+        [
+            "..........",
+            ".......##.",
+            ".........#",
+            "......#..#",
+            "......#...",
+            ".....#....",
+            "...##.....",
+            "..........",
+        ]
+    );
+}
+
+#[test]
+fn polygons_leave_out_their_right_and_bottom_edges_when_filled() {
+    assert_eq!(
+        render(|canvas| {
+            fill_polygon(canvas, &[(0, 0), (4, 0), (4, 4), (0, 4)], false).unwrap();
+            draw_polygon(canvas, &[(6, 0), (9, 3), (6, 3)], false).unwrap();
+            fill_polygon(canvas, &[(6, 5), (12, 5), (12, 12)], false).unwrap();
+            fill_polygon(canvas, &[(1, 6), (3, 6)], false).unwrap();
+        }),
+        [
+            "####..#...",
+            "####..##..",
+            "####..#.#.",
+            "####..####",
+            "..........",
+            "......####",
+            ".......###",
+            "........##",
+        ]
+    );
+}
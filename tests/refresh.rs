@@ -0,0 +1,69 @@
+use e_ink_graphics_library::refresh::{RefreshPolicy, RefreshScheduler};
+
+/// Scheduler past its first refresh, which is always full.
+fn scheduler(policy: RefreshPolicy) -> RefreshScheduler {
+    let mut scheduler = RefreshScheduler::new(policy);
+    assert!(scheduler.is_full_refresh_due(0, 100));
+    scheduler.record_refresh(true);
+    scheduler
+}
+
+#[test]
+fn fixed_count_policy_is_due_after_the_partial_refreshes() {
+    let mut scheduler = scheduler(RefreshPolicy::FixedCount { count: 2 });
+    assert!(!scheduler.is_full_refresh_due(100, 100));
+    scheduler.record_refresh(false);
+    assert!(!scheduler.is_full_refresh_due(100, 100));
+    scheduler.record_refresh(false);
+    assert!(scheduler.is_full_refresh_due(0, 100));
+    assert_eq!(scheduler.partial_refresh_count(), 2);
+
+    scheduler.record_refresh(true);
+    assert_eq!(scheduler.partial_refresh_count(), 0);
+    assert!(!scheduler.is_full_refresh_due(0, 100));
+}
+
+#[test]
+fn changed_ratio_policy_is_due_from_the_percentage() {
+    let mut scheduler = scheduler(RefreshPolicy::ChangedRatio { percent: 25 });
+    assert!(!scheduler.is_full_refresh_due(0, 200));
+    assert!(!scheduler.is_full_refresh_due(49, 200));
+    assert!(scheduler.is_full_refresh_due(50, 200));
+    // the partial refreshes do not add up
+    scheduler.record_refresh(false);
+    assert!(!scheduler.is_full_refresh_due(49, 200));
+}
+
+#[test]
+fn interval_policy_is_due_once_the_time_elapsed() {
+    let mut scheduler = scheduler(RefreshPolicy::Interval { millis: 1000 });
+    scheduler.advance_time(600);
+    assert!(!scheduler.is_full_refresh_due(100, 100));
+    scheduler.advance_time(399);
+    assert!(!scheduler.is_full_refresh_due(100, 100));
+    scheduler.advance_time(1);
+    assert!(scheduler.is_full_refresh_due(0, 100));
+    assert_eq!(scheduler.elapsed_millis(), 1000);
+
+    scheduler.record_refresh(true);
+    assert_eq!(scheduler.elapsed_millis(), 0);
+    assert!(!scheduler.is_full_refresh_due(0, 100));
+}
+
+#[test]
+fn always_full_policy_is_always_due() {
+    let mut scheduler = scheduler(RefreshPolicy::AlwaysFull);
+    assert!(scheduler.is_full_refresh_due(0, 100));
+    scheduler.record_refresh(true);
+    assert!(scheduler.is_full_refresh_due(0, 100));
+}
+
+#[test]
+fn always_partial_policy_is_only_due_for_the_first_refresh() {
+    let mut scheduler = scheduler(RefreshPolicy::AlwaysPartial);
+    for _ in 0..10 {
+        assert!(!scheduler.is_full_refresh_due(100, 100));
+        scheduler.advance_time(u32::MAX);
+        scheduler.record_refresh(false);
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "simulator")]
+
+use e_ink_graphics_library::{
+    BWDisplay, BWDraw, refresh::RefreshPolicy, simulator::SimulatorDisplay,
+};
+
+/// 2x2 display showing black pixels on its diagonal.
+fn diagonal() -> SimulatorDisplay {
+    let mut display = SimulatorDisplay::new(2, 2);
+    display.fill(true).unwrap();
+    display.set_pixel(0, 0, false).unwrap();
+    display.set_pixel(1, 1, false).unwrap();
+    display.refresh(true).unwrap();
+    display
+}
+
+#[test]
+fn pbm_snapshot_inverts_the_frame() {
+    let mut pbm = Vec::new();
+    diagonal().write_pbm(&mut pbm).unwrap();
+
+    assert_eq!(pbm, b"P4\n2 2\n\x80\x40");
+}
+
+#[test]
+fn png_snapshot_is_a_stored_zlib_stream() {
+    let mut png = Vec::new();
+    diagonal().write_png(&mut png).unwrap();
+
+    #[rustfmt::skip]
+    let expected = [
+        // signature
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a,
+        // IHDR: 2x2, bit depth 1, grayscale, then its CRC-32
+        0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x5a, 0xcd, 0x30, 0x89,
+        // IDAT: zlib header, final stored block of 4 bytes, scanlines, Adler-32, CRC-32
+        0x00, 0x00, 0x00, 0x0f, 0x49, 0x44, 0x41, 0x54,
+        0x78, 0x01, 0x01, 0x04, 0x00, 0xfb, 0xff,
+        0x00, 0x7f, 0x00, 0xbf,
+        0x02, 0x40, 0x01, 0x3f,
+        0xe8, 0x1e, 0x3d, 0x1a,
+        // IEND
+        0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+    ];
+    assert_eq!(png, expected);
+}
+
+#[test]
+fn refreshes_are_counted_by_kind() {
+    let mut display =
+        SimulatorDisplay::new(16, 8).with_refresh_policy(RefreshPolicy::FixedCount { count: 2 });
+    display.fill(true).unwrap();
+    for i in 0..7 {
+        display.set_pixel(i, 0, false).unwrap();
+        display.refresh(false).unwrap();
+    }
+    // full, partial, partial, full, partial, partial, full
+    assert_eq!(display.full_refreshes(), 3);
+    assert_eq!(display.partial_refreshes(), 4);
+
+    display.refresh(true).unwrap();
+    assert_eq!(display.full_refreshes(), 4);
+    assert_eq!(display.displayed_frame()[0], 0x01);
+}
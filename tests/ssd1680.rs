@@ -0,0 +1,552 @@
+#![cfg(feature = "ssd1680")]
+
+mod common;
+
+use common::{BUFFER_SIZE, Event, Pin, display, grayscale_display, tri_color_display};
+use e_ink_graphics_library::{
+    BWDisplay, BWDraw, Gray4, TriColor, TriColorDisplay,
+    ssd1680::{GRAY4_WAVEFORM, TemperatureBand, Waveform, Waveforms},
+};
+
+const WRITE_BW_RAM: u8 = 0x24;
+const WRITE_RED_RAM: u8 = 0x26;
+const MASTER_ACTIVATION: u8 = 0x20;
+const DEEP_SLEEP: u8 = 0x10;
+const WRITE_LUT: u8 = 0x32;
+const DISPLAY_UPDATE_CONTROL_2: u8 = 0x22;
+
+fn position(commands: &[(u8, Vec<u8>)], command: u8, data: &[u8]) -> Option<usize> {
+    commands
+        .iter()
+        .position(|(c, d)| *c == command && d.as_slice() == data)
+}
+
+fn white_frame() -> Vec<u8> {
+    vec![0xff; BUFFER_SIZE]
+}
+
+/// RAM window of the whole 16x8 mock panel.
+fn full_window() -> Vec<(u8, Vec<u8>)> {
+    vec![
+        (0x44, vec![0, 1]),
+        (0x45, vec![0, 0, 7, 0]),
+        (0x4e, vec![0]),
+        (0x4f, vec![0, 0]),
+    ]
+}
+
+/// Software reset and configuration of the 16x8 mock panel.
+fn init_commands() -> Vec<(u8, Vec<u8>)> {
+    let mut commands = vec![(0x12, vec![]), (0x01, vec![7, 0, 0]), (0x11, vec![0x03])];
+    commands.extend(full_window());
+    commands.extend([(0x3c, vec![0x05]), (0x18, vec![0x80])]);
+    commands
+}
+
+/// Events following the first master activation.
+fn after_activation(events: &[Event]) -> &[Event] {
+    let activation = events
+        .iter()
+        .position(|event| *event == Event::Spi(vec![MASTER_ACTIVATION]))
+        .unwrap();
+    &events[activation + 1..]
+}
+
+#[test]
+fn first_refresh_resets_and_uploads_whole_frame() {
+    let (mut display, log) = display();
+    display.fill(true).unwrap();
+    display.set_pixel(0, 0, false).unwrap();
+    display.refresh(false).unwrap();
+
+    let events = log.events();
+    let first_spi = events
+        .iter()
+        .position(|event| matches!(event, Event::Spi(_)))
+        .unwrap();
+    assert_eq!(log.resets(), 1);
+    assert!(events[..first_spi].contains(&Event::Pin(Pin::Reset, false)));
+
+    let mut frame = white_frame();
+    frame[0] = 0x7f;
+    let commands = log.commands();
+    let upload = position(&commands, WRITE_BW_RAM, &frame).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    assert!(upload < activation);
+    assert_eq!(commands.last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn wake_resets_and_configures_the_controller() {
+    let (mut display, log) = display();
+    display.wake().unwrap();
+
+    assert_eq!(
+        log.events()[..6],
+        [
+            Event::Pin(Pin::Reset, false),
+            Event::Pin(Pin::Reset, true),
+            Event::Busy(false),
+            Event::Pin(Pin::DataCommand, false),
+            Event::Spi(vec![0x12]),
+            Event::Busy(false),
+        ]
+    );
+    assert_eq!(log.commands(), init_commands());
+    assert_eq!(log.events().last(), Some(&Event::Busy(false)));
+}
+
+#[test]
+fn full_refresh_sends_the_exact_transcript() {
+    let (mut display, log) = display();
+    display.fill(true).unwrap();
+    display.set_pixel(0, 0, false).unwrap();
+    display.refresh(false).unwrap();
+
+    let mut frame = white_frame();
+    frame[0] = 0x7f;
+    let mut expected = init_commands();
+    expected.extend(full_window());
+    expected.push((WRITE_BW_RAM, frame));
+    expected.extend(full_window());
+    expected.extend([
+        (DISPLAY_UPDATE_CONTROL_2, vec![0xF7]),
+        (MASTER_ACTIVATION, vec![]),
+        (DEEP_SLEEP, vec![0x01]),
+    ]);
+    assert_eq!(log.commands(), expected);
+}
+
+#[test]
+fn partial_refresh_sends_the_exact_transcript() {
+    let (mut display, log) = display();
+    display.set_auto_sleep(false);
+    display.fill(true).unwrap();
+    display.refresh(false).unwrap();
+    log.clear();
+
+    display.set_pixel(9, 3, false).unwrap();
+    display.refresh(false).unwrap();
+
+    // byte 1 of row 3
+    let window = [
+        (0x44, vec![1, 1]),
+        (0x45, vec![3, 0, 3, 0]),
+        (0x4e, vec![1]),
+        (0x4f, vec![3, 0]),
+    ];
+    let mut expected = window.to_vec();
+    expected.push((WRITE_BW_RAM, vec![0b1011_1111]));
+    expected.extend(full_window());
+    expected.extend([
+        (DISPLAY_UPDATE_CONTROL_2, vec![0xFF]),
+        (MASTER_ACTIVATION, vec![]),
+    ]);
+    expected.extend(window);
+    expected.push((WRITE_RED_RAM, vec![0b1011_1111]));
+    expected.extend(full_window());
+    assert_eq!(log.commands(), expected);
+}
+
+#[test]
+fn refresh_waits_until_the_panel_is_idle() {
+    let (mut display, log) = display();
+    log.set_update_polls(3);
+    display.refresh(false).unwrap();
+
+    assert_eq!(
+        after_activation(&log.events()),
+        [
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(false),
+            Event::Pin(Pin::DataCommand, false),
+            Event::Spi(vec![DEEP_SLEEP]),
+            Event::Pin(Pin::DataCommand, true),
+            Event::Spi(vec![0x01]),
+        ]
+    );
+}
+
+#[test]
+fn auto_sleep_reinitializes_the_controller_for_each_refresh() {
+    let (mut display, log) = display();
+    display.refresh(false).unwrap();
+    display.refresh(false).unwrap();
+
+    assert_eq!(log.resets(), 2);
+    let sleeps = log
+        .commands()
+        .iter()
+        .filter(|(command, _)| *command == DEEP_SLEEP)
+        .count();
+    assert_eq!(sleeps, 2);
+}
+
+#[test]
+fn awake_controller_is_not_reset_between_refreshes() {
+    let (mut display, log) = display();
+    display.set_auto_sleep(false);
+    display.refresh(false).unwrap();
+    display.refresh(false).unwrap();
+
+    assert_eq!(log.resets(), 1);
+    assert!(
+        log.commands()
+            .iter()
+            .all(|(command, _)| *command != DEEP_SLEEP)
+    );
+    assert!(display.is_awake());
+
+    display.sleep().unwrap();
+    assert!(!display.is_awake());
+    assert_eq!(log.commands().last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn partial_refresh_uploads_changed_window() {
+    let (mut display, log) = display();
+    display.set_auto_sleep(false);
+    display.fill(true).unwrap();
+    display.refresh(false).unwrap();
+    log.clear();
+
+    display.set_pixel(9, 3, false).unwrap();
+    display.refresh(false).unwrap();
+    let commands = log.commands();
+
+    assert_eq!(log.resets(), 0);
+    // byte 1 of row 3, in both directions
+    let window = [
+        (0x44, vec![1, 1]),
+        (0x45, vec![3, 0, 3, 0]),
+        (0x4e, vec![1]),
+        (0x4f, vec![3, 0]),
+    ];
+    assert!(commands.starts_with(&window));
+    let upload = position(&commands, WRITE_BW_RAM, &[0b1011_1111]).unwrap();
+    assert_eq!(upload, window.len());
+    assert!(position(&commands, WRITE_BW_RAM, &white_frame()).is_none());
+
+    // the new frame becomes the reference of the next partial refresh
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    let reference = position(&commands, WRITE_RED_RAM, &[0b1011_1111]).unwrap();
+    assert!(upload < activation && activation < reference);
+}
+
+#[test]
+fn unchanged_frame_is_not_uploaded_again() {
+    let (mut display, log) = display();
+    display.set_auto_sleep(false);
+    display.refresh(false).unwrap();
+    log.clear();
+
+    display.refresh(false).unwrap();
+    let commands = log.commands();
+    assert!(commands.iter().all(|(command, _)| *command != WRITE_BW_RAM));
+    assert!(position(&commands, MASTER_ACTIVATION, &[]).is_some());
+}
+
+#[test]
+fn partial_refresh_after_wake_uploads_reference_frame() {
+    let (mut display, log) = display();
+    display.fill(true).unwrap();
+    display.refresh(false).unwrap();
+    log.clear();
+
+    display.set_pixel(0, 0, false).unwrap();
+    display.refresh(false).unwrap();
+    let commands = log.commands();
+
+    let mut frame = white_frame();
+    frame[0] = 0x7f;
+    let reference = position(&commands, WRITE_RED_RAM, &white_frame()).unwrap();
+    let upload = position(&commands, WRITE_BW_RAM, &frame).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    assert!(reference < activation && upload < activation);
+    assert_eq!(display.partial_refresh_count(), 1);
+}
+
+#[test]
+fn forced_full_refresh_uploads_whole_frame() {
+    let (mut display, log) = display();
+    display.set_auto_sleep(false);
+    display.fill(true).unwrap();
+    display.refresh(false).unwrap();
+    log.clear();
+
+    display.set_pixel(15, 7, false).unwrap();
+    display.refresh(true).unwrap();
+    let commands = log.commands();
+
+    let mut frame = white_frame();
+    frame[BUFFER_SIZE - 1] = 0xfe;
+    let upload = position(&commands, WRITE_BW_RAM, &frame).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    let reference = position(&commands, WRITE_RED_RAM, &frame).unwrap();
+    assert!(upload < activation && activation < reference);
+    assert_eq!(display.partial_refresh_count(), 0);
+}
+
+#[test]
+fn tri_color_refresh_uploads_both_planes() {
+    let (mut display, log) = tri_color_display();
+    display.fill(TriColor::White).unwrap();
+    display.set_pixel(0, 0, TriColor::Red).unwrap();
+    display.set_pixel(1, 0, TriColor::Black).unwrap();
+    display.refresh().unwrap();
+    let commands = log.commands();
+
+    let mut bw = white_frame();
+    bw[0] = 0b1011_1111;
+    let mut red = vec![0; BUFFER_SIZE];
+    red[0] = 0x80;
+    let bw_upload = position(&commands, WRITE_BW_RAM, &bw).unwrap();
+    let red_upload = position(&commands, WRITE_RED_RAM, &red).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    assert!(bw_upload < activation && red_upload < activation);
+    assert_eq!(commands.last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn tri_color_refresh_waits_for_the_panel() {
+    let (mut display, log) = tri_color_display();
+    log.set_update_polls(4);
+    display.refresh().unwrap();
+
+    let after = after_activation(&log.events()).to_vec();
+    assert_eq!(
+        after[..5],
+        [
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(false),
+        ]
+    );
+    assert_eq!(log.busy_polls(), 4);
+    assert_eq!(log.commands().last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn gray_refresh_splits_the_levels_and_loads_the_waveform() {
+    let (mut display, log) = grayscale_display();
+    display.fill_gray(Gray4::White);
+    // one pixel of each level
+    display
+        .draw_gray_buffer(&[0b0001_1011], 0, 0, 4, 1)
+        .unwrap();
+    assert_eq!(display.get_gray_pixel(1, 0).unwrap(), Gray4::DarkGray);
+    display.refresh_gray().unwrap();
+    let commands = log.commands();
+
+    let mut high = white_frame();
+    high[0] = 0b0011_1111;
+    let mut low = white_frame();
+    low[0] = 0b0101_1111;
+    let lut = position(&commands, WRITE_LUT, &GRAY4_WAVEFORM.lut).unwrap();
+    let high_upload = position(&commands, WRITE_BW_RAM, &high).unwrap();
+    let low_upload = position(&commands, WRITE_RED_RAM, &low).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    assert!(lut < activation && high_upload < activation && low_upload < activation);
+    assert_eq!(commands.last().unwrap().0, DEEP_SLEEP);
+
+    // black and white drawing keeps both planes in sync
+    display.set_pixel(0, 0, true).unwrap();
+    assert_eq!(display.get_gray_pixel(0, 0).unwrap(), Gray4::White);
+}
+
+static COLD: Waveform = Waveform::from_bytes([0x11; 159]);
+static WARM: Waveform = Waveform::from_bytes([0x22; 159]);
+static BANDS: [TemperatureBand; 2] = [
+    TemperatureBand {
+        max_temperature: 5,
+        waveforms: Waveforms {
+            full: Some(&COLD),
+            partial: None,
+            fast: None,
+        },
+    },
+    TemperatureBand {
+        max_temperature: 50,
+        waveforms: Waveforms {
+            full: Some(&WARM),
+            partial: None,
+            fast: None,
+        },
+    },
+];
+
+#[test]
+fn custom_waveform_is_chosen_by_temperature() {
+    let (display, log) = display();
+    // the mock sensor reads 0 °C
+    let mut display = display.with_waveforms(&BANDS);
+    display.refresh(true).unwrap();
+    let commands = log.commands();
+
+    let sensing = position(&commands, DISPLAY_UPDATE_CONTROL_2, &[0xB1]).unwrap();
+    let lut = position(&commands, WRITE_LUT, &COLD.lut).unwrap();
+    let activation = position(&commands, DISPLAY_UPDATE_CONTROL_2, &[0xC7]).unwrap();
+    assert!(sensing < lut && lut < activation);
+    assert!(position(&commands, WRITE_LUT, &WARM.lut).is_none());
+
+    // partial refreshes without a custom waveform use the OTP
+    log.clear();
+    display.set_pixel(0, 0, false).unwrap();
+    display.refresh(false).unwrap();
+    assert!(
+        log.commands()
+            .iter()
+            .all(|(command, _)| *command != WRITE_LUT)
+    );
+}
+
+#[test]
+fn supplied_temperature_skips_the_sensor() {
+    let (display, log) = display();
+    let mut display = display.with_waveforms(&BANDS);
+    display.set_temperature(Some(20));
+    assert_eq!(display.temperature(), Some(20));
+    display.refresh(true).unwrap();
+    let commands = log.commands();
+
+    assert!(position(&commands, DISPLAY_UPDATE_CONTROL_2, &[0xB1]).is_none());
+    assert!(commands.iter().all(|(command, _)| *command != 0x1B));
+    assert!(position(&commands, WRITE_LUT, &WARM.lut).is_some());
+}
+
+static FAST: Waveform = Waveform::from_bytes([0x33; 159]);
+static FAST_BANDS: [TemperatureBand; 1] = [TemperatureBand {
+    max_temperature: 50,
+    waveforms: Waveforms {
+        full: Some(&WARM),
+        partial: None,
+        fast: Some(&FAST),
+    },
+}];
+
+/// Upload of `waveform` to the LUT and voltage registers.
+fn waveform_commands(waveform: &Waveform) -> Vec<(u8, Vec<u8>)> {
+    vec![
+        (WRITE_LUT, waveform.lut.to_vec()),
+        (0x3f, vec![waveform.end_option]),
+        (0x03, vec![waveform.gate_voltage]),
+        (0x04, waveform.source_voltages.to_vec()),
+        (0x2c, vec![waveform.vcom]),
+    ]
+}
+
+/// Commands of a fast refresh of a white frame from a sleeping display, with the waveform
+/// upload and the display update sequence of the refresh.
+fn fast_refresh_transcript(waveform: Option<&Waveform>, sequence: u8) -> Vec<(u8, Vec<u8>)> {
+    let mut expected = init_commands();
+    expected.extend(full_window());
+    expected.push((WRITE_BW_RAM, white_frame()));
+    expected.extend(full_window());
+    expected.extend(waveform.map(waveform_commands).unwrap_or_default());
+    expected.extend([
+        (DISPLAY_UPDATE_CONTROL_2, vec![sequence]),
+        (MASTER_ACTIVATION, vec![]),
+        (DEEP_SLEEP, vec![0x01]),
+    ]);
+    expected
+}
+
+#[test]
+fn fast_refresh_loads_the_fast_waveform() {
+    let (display, log) = display();
+    let mut display = display.with_waveforms(&FAST_BANDS);
+    display.set_temperature(Some(20));
+    display.fill(true).unwrap();
+    display.refresh_fast().unwrap();
+
+    assert_eq!(log.commands(), fast_refresh_transcript(Some(&FAST), 0xC7));
+    assert_eq!(display.partial_refresh_count(), 0);
+}
+
+#[test]
+fn fast_refresh_without_fast_waveform_uses_the_full_one() {
+    let (display, log) = display();
+    let mut display = display.with_waveforms(&BANDS);
+    display.set_temperature(Some(20));
+    display.fill(true).unwrap();
+    display.refresh_fast().unwrap();
+
+    assert_eq!(log.commands(), fast_refresh_transcript(Some(&WARM), 0xC7));
+}
+
+#[test]
+fn fast_refresh_without_custom_waveforms_runs_the_full_otp_update() {
+    let (mut display, log) = display();
+    display.fill(true).unwrap();
+    display.refresh_fast().unwrap();
+
+    assert_eq!(log.commands(), fast_refresh_transcript(None, 0xF7));
+}
+
+#[test]
+fn started_refresh_ends_when_polled() {
+    let (mut display, log) = display();
+    display.start_refresh(false).unwrap();
+    assert!(display.is_refreshing());
+    assert_eq!(log.commands().last().unwrap().0, MASTER_ACTIVATION);
+
+    // drawing during the refresh does not touch the controller
+    log.clear();
+    display.fill(false).unwrap();
+    assert!(log.commands().is_empty());
+
+    assert!(display.poll_refresh().unwrap());
+    assert!(!display.is_refreshing());
+    assert_eq!(log.commands().last().unwrap().0, DEEP_SLEEP);
+    assert!(display.poll_refresh().unwrap());
+}
+
+#[test]
+fn poll_refresh_reports_the_busy_panel() {
+    let (mut display, log) = display();
+    log.set_update_polls(2);
+    display.start_refresh(false).unwrap();
+
+    assert!(!display.poll_refresh().unwrap());
+    assert!(!display.poll_refresh().unwrap());
+    assert!(
+        log.commands()
+            .iter()
+            .all(|(command, _)| *command != DEEP_SLEEP)
+    );
+    assert!(display.poll_refresh().unwrap());
+    assert_eq!(log.busy_polls(), 2);
+    assert_eq!(log.commands().last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn double_buffered_refresh_uploads_the_swapped_frame_when_polled() {
+    let (mut display, log) = display();
+    display.set_double_buffering(true);
+    display.fill(true).unwrap();
+    display.set_pixel(0, 0, false).unwrap();
+    display.start_refresh(false).unwrap();
+    assert!(
+        log.commands()
+            .iter()
+            .all(|(command, _)| *command != WRITE_BW_RAM)
+    );
+
+    // the back buffer keeps the latest image
+    assert!(display.get_pixel(1, 0).unwrap());
+    display.set_pixel(1, 0, false).unwrap();
+    while !display.poll_refresh().unwrap() {}
+
+    let mut frame = white_frame();
+    frame[0] = 0x7f;
+    let commands = log.commands();
+    let upload = position(&commands, WRITE_BW_RAM, &frame).unwrap();
+    let activation = position(&commands, MASTER_ACTIVATION, &[]).unwrap();
+    assert!(upload < activation);
+    assert_eq!(commands.last().unwrap().0, DEEP_SLEEP);
+    assert!(!display.get_pixel(1, 0).unwrap());
+}
@@ -0,0 +1,120 @@
+#![cfg(all(feature = "ssd1680", feature = "async"))]
+
+mod common;
+
+use common::{BUFFER_SIZE, Event, async_display, block_on};
+use e_ink_graphics_library::{AsyncBWDisplay, BWDraw};
+
+const WRITE_BW_RAM: u8 = 0x24;
+const WRITE_RED_RAM: u8 = 0x26;
+const MASTER_ACTIVATION: u8 = 0x20;
+const DEEP_SLEEP: u8 = 0x10;
+
+#[test]
+fn async_refresh_uploads_the_frame_and_sleeps() {
+    let (mut display, log) = async_display();
+    display.fill(true).unwrap();
+    display.set_pixel(0, 0, false).unwrap();
+    block_on(display.refresh(false)).unwrap();
+
+    let mut frame = vec![0xff; BUFFER_SIZE];
+    frame[0] = 0x7f;
+    let commands = log.commands();
+    let upload = commands
+        .iter()
+        .position(|(c, d)| *c == WRITE_BW_RAM && *d == frame)
+        .unwrap();
+    let activation = commands
+        .iter()
+        .position(|(c, _)| *c == MASTER_ACTIVATION)
+        .unwrap();
+    assert_eq!(log.resets(), 1);
+    assert!(upload < activation);
+    assert_eq!(commands.last().unwrap().0, DEEP_SLEEP);
+    assert!(!display.is_awake());
+}
+
+#[test]
+fn async_partial_refresh_uploads_the_changed_window() {
+    let (mut display, log) = async_display();
+    display.set_auto_sleep(false);
+    display.fill(true).unwrap();
+    block_on(display.refresh(false)).unwrap();
+    log.clear();
+
+    display.set_pixel(9, 3, false).unwrap();
+    block_on(display.refresh(false)).unwrap();
+
+    // same RAM writes as the blocking display: byte 1 of row 3, then the reference
+    let window = [
+        (0x44, vec![1, 1]),
+        (0x45, vec![3, 0, 3, 0]),
+        (0x4e, vec![1]),
+        (0x4f, vec![3, 0]),
+    ];
+    let full_window = [
+        (0x44, vec![0, 1]),
+        (0x45, vec![0, 0, 7, 0]),
+        (0x4e, vec![0]),
+        (0x4f, vec![0, 0]),
+    ];
+    let mut expected = window.to_vec();
+    expected.push((WRITE_BW_RAM, vec![0b1011_1111]));
+    expected.extend(full_window.clone());
+    expected.extend([(0x22, vec![0xff]), (MASTER_ACTIVATION, vec![])]);
+    expected.extend(window);
+    expected.push((WRITE_RED_RAM, vec![0b1011_1111]));
+    expected.extend(full_window);
+    assert_eq!(log.commands(), expected);
+    assert_eq!(display.partial_refresh_count(), 1);
+}
+
+#[test]
+fn async_refresh_awaits_the_end_of_the_update() {
+    let (mut display, log) = async_display();
+    log.set_update_polls(3);
+    block_on(display.refresh(false)).unwrap();
+
+    let events = log.events();
+    let activation = events
+        .iter()
+        .position(|event| *event == Event::Spi(vec![MASTER_ACTIVATION]))
+        .unwrap();
+    assert_eq!(
+        events[activation + 1..activation + 5],
+        [
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(true),
+            Event::Busy(false),
+        ]
+    );
+    assert_eq!(log.commands().last().unwrap().0, DEEP_SLEEP);
+}
+
+#[test]
+fn async_fast_refresh_runs_the_full_otp_update() {
+    let (mut display, log) = async_display();
+    display.fill(true).unwrap();
+    block_on(display.refresh_fast()).unwrap();
+
+    // the async display has no custom waveforms, fast refreshes are full ones
+    let commands = log.commands();
+    let upload = commands
+        .iter()
+        .position(|(c, d)| *c == WRITE_BW_RAM && *d == vec![0xff; BUFFER_SIZE])
+        .unwrap();
+    assert_eq!(
+        commands[upload + 1..],
+        [
+            (0x44, vec![0, 1]),
+            (0x45, vec![0, 0, 7, 0]),
+            (0x4e, vec![0]),
+            (0x4f, vec![0, 0]),
+            (0x22, vec![0xf7]),
+            (MASTER_ACTIVATION, vec![]),
+            (DEEP_SLEEP, vec![0x01]),
+        ]
+    );
+    assert_eq!(display.partial_refresh_count(), 0);
+}